@@ -1,18 +1,34 @@
 // Anthropic Claude API client for company classification (ported from Python)
 
 use anyhow::{Context, Result};
-use reqwest::Client;
+use futures::stream::{self, StreamExt};
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::sleep;
 use tracing::{debug, warn};
 
+use crate::cache::ClassificationCache;
+
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
 const MODEL: &str = "claude-3-haiku-20240307";
+const CLASSIFY_TOOL_NAME: &str = "classify_company";
+const DEFAULT_CACHE_DIR: &str = ".cache/classifications";
+const MAX_RETRIES: u32 = 3;
+/// Default concurrency for `batch_classify` when the caller doesn't override it
+pub const DEFAULT_CONCURRENCY: usize = 5;
 
 #[derive(Clone)]
 pub struct AnthropicClassifier {
     api_key: String,
     client: Client,
     model: String,
+    cache: Arc<ClassificationCache>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -28,6 +44,37 @@ pub struct Classification {
     pub raw_response: String,
 }
 
+/// Live counters for an in-flight `batch_classify` run, shared across every concurrent
+/// worker via `Arc` so long runs stay observable
+#[derive(Debug, Default)]
+struct BatchStats {
+    requests_sent: AtomicU64,
+    cache_hits: AtomicU64,
+    retries: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl BatchStats {
+    fn snapshot(&self) -> BatchClassifyStats {
+        BatchClassifyStats {
+            requests_sent: self.requests_sent.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Point-in-time snapshot of `BatchStats`, returned alongside a `batch_classify` run's
+/// results so callers can observe how much network traffic and cache reuse it took
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct BatchClassifyStats {
+    pub requests_sent: u64,
+    pub cache_hits: u64,
+    pub retries: u64,
+    pub failures: u64,
+}
+
 // Anthropic API request/response types
 #[derive(Debug, Serialize)]
 struct AnthropicRequest {
@@ -35,6 +82,10 @@ struct AnthropicRequest {
     max_tokens: u32,
     temperature: f32,
     messages: Vec<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<ToolChoice>,
 }
 
 #[derive(Debug, Serialize)]
@@ -43,17 +94,79 @@ struct Message {
     content: String,
 }
 
+/// A single Anthropic tool definition: `input_schema` is a JSON Schema object, so a forced
+/// tool call is guaranteed to produce an `input` conforming to it instead of free-form prose
+#[derive(Debug, Serialize)]
+struct Tool {
+    name: String,
+    description: String,
+    input_schema: serde_json::Value,
+}
+
+/// Forces the model to call a specific tool rather than choosing one or replying in text
+#[derive(Debug, Serialize)]
+struct ToolChoice {
+    #[serde(rename = "type")]
+    choice_type: String,
+    name: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct AnthropicResponse {
     content: Vec<Content>,
 }
 
 #[derive(Debug, Deserialize)]
-struct Content {
-    text: String,
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Content {
+    Text { text: String },
+    ToolUse { input: serde_json::Value },
+    #[serde(other)]
+    Other,
 }
 
-// Classification result from AI (matches JSON format)
+/// The JSON Schema describing `classify_company`'s forced tool-use `input`, matching
+/// `ClassificationData` field-for-field
+fn classification_tool() -> Tool {
+    Tool {
+        name: CLASSIFY_TOOL_NAME.to_string(),
+        description: "Record the space-infrastructure classification assessment for a company."
+            .to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "is_space_related": {
+                    "type": "boolean",
+                    "description": "True if any meaningful portion of the business involves space infrastructure"
+                },
+                "space_revenue_pct": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 100,
+                    "description": "Estimated percentage of total revenue from space activities"
+                },
+                "confidence": {
+                    "type": "string",
+                    "enum": ["high", "medium", "low"]
+                },
+                "segments": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "enum": ["Launch", "Satellites", "Ground", "Components"]
+                    }
+                },
+                "reasoning": {
+                    "type": "string",
+                    "description": "2-3 sentences explaining the assessment and space_revenue_pct estimate"
+                }
+            },
+            "required": ["is_space_related", "space_revenue_pct", "confidence", "segments", "reasoning"]
+        }),
+    }
+}
+
+// Classification result from AI (matches the classify_company tool's input schema)
 #[derive(Debug, Deserialize)]
 struct ClassificationData {
     is_space_related: bool,
@@ -63,9 +176,23 @@ struct ClassificationData {
     reasoning: String,
 }
 
+/// Parse Anthropic's `retry-after` header (seconds) off a rate-limited/errored response,
+/// so backoff honors the server's own estimate instead of guessing
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let seconds: u64 = response.headers().get("retry-after")?.to_str().ok()?.parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
 impl AnthropicClassifier {
-    /// Create new classifier with API key from environment or parameter
+    /// Create new classifier with API key from environment or parameter, caching
+    /// classifications on disk under `DEFAULT_CACHE_DIR`
     pub fn new(api_key: Option<String>) -> Result<Self> {
+        Self::with_cache_dir(api_key, DEFAULT_CACHE_DIR)
+    }
+
+    /// Like `new`, but with an explicit on-disk cache directory - useful for tests or
+    /// batch runs that want an isolated cache
+    pub fn with_cache_dir(api_key: Option<String>, cache_dir: impl Into<PathBuf>) -> Result<Self> {
         let api_key = api_key
             .or_else(|| std::env::var("ANTHROPIC_API_KEY").ok())
             .context("ANTHROPIC_API_KEY must be set in environment or passed to constructor")?;
@@ -74,10 +201,13 @@ impl AnthropicClassifier {
             api_key,
             client: Client::new(),
             model: MODEL.to_string(),
+            cache: Arc::new(ClassificationCache::new(cache_dir)?),
         })
     }
 
-    /// Classify a company as space-related and estimate space revenue percentage
+    /// Classify a company as space-related and estimate space revenue percentage, serving
+    /// from the on-disk cache when `ticker + company_name + description` has been seen
+    /// before
     pub async fn classify_company(
         &self,
         ticker: &str,
@@ -85,6 +215,30 @@ impl AnthropicClassifier {
         description: &str,
         additional_context: Option<&str>,
     ) -> Result<Classification> {
+        self.classify_company_tracked(ticker, company_name, description, additional_context, None)
+            .await
+    }
+
+    /// Shared implementation behind `classify_company` and `batch_classify`: checks the
+    /// on-disk cache, retries the network call with exponential backoff on 429/5xx, and
+    /// bumps `stats` (when given one) for cache hits, requests sent, retries and failures
+    async fn classify_company_tracked(
+        &self,
+        ticker: &str,
+        company_name: &str,
+        description: &str,
+        additional_context: Option<&str>,
+        stats: Option<&BatchStats>,
+    ) -> Result<Classification> {
+        let cache_key = ClassificationCache::key_for(ticker, company_name, description);
+        if let Some(cached) = self.cache.get(&cache_key) {
+            if let Some(stats) = stats {
+                stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+            }
+            debug!("Cache hit for {} ({})", company_name, ticker);
+            return Ok(cached);
+        }
+
         let prompt = self.build_classification_prompt(
             ticker,
             company_name,
@@ -102,33 +256,98 @@ impl AnthropicClassifier {
                 role: "user".to_string(),
                 content: prompt,
             }],
+            tools: Some(vec![classification_tool()]),
+            tool_choice: Some(ToolChoice {
+                choice_type: "tool".to_string(),
+                name: CLASSIFY_TOOL_NAME.to_string(),
+            }),
         };
 
-        let response = self
-            .client
-            .post(ANTHROPIC_API_URL)
-            .header("x-api-key", &self.api_key)
-            .header("anthropic-version", "2023-06-01")
-            .header("content-type", "application/json")
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send request to Anthropic API")?;
+        let api_response = match self.send_with_retry(&request, stats).await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(stats) = stats {
+                    stats.failures.fetch_add(1, Ordering::Relaxed);
+                }
+                return Err(e);
+            }
+        };
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Anthropic API error {}: {}", status, error_text);
+        let tool_input = api_response
+            .content
+            .into_iter()
+            .find_map(|block| match block {
+                Content::ToolUse { input } => Some(input),
+                _ => None,
+            })
+            .context("Anthropic response did not include a classify_company tool_use block")?;
+
+        let classification = self.parse_tool_input(ticker, company_name, tool_input)?;
+
+        if let Err(e) = self.cache.put(&cache_key, &classification) {
+            warn!("Failed to cache classification for {}: {}", ticker, e);
         }
 
-        let api_response: AnthropicResponse = response
-            .json()
-            .await
-            .context("Failed to parse Anthropic API response")?;
+        Ok(classification)
+    }
 
-        let response_text = &api_response.content[0].text;
+    /// Send `request`, retrying on HTTP 429/5xx with exponential backoff. Honors
+    /// Anthropic's `retry-after` header when present, falling back to `2^retries` seconds
+    /// otherwise.
+    async fn send_with_retry(
+        &self,
+        request: &AnthropicRequest,
+        stats: Option<&BatchStats>,
+    ) -> Result<AnthropicResponse> {
+        let mut retries = 0;
+        loop {
+            if let Some(stats) = stats {
+                stats.requests_sent.fetch_add(1, Ordering::Relaxed);
+            }
+
+            let response = self
+                .client
+                .post(ANTHROPIC_API_URL)
+                .header("x-api-key", &self.api_key)
+                .header("anthropic-version", "2023-06-01")
+                .header("content-type", "application/json")
+                .json(request)
+                .send()
+                .await
+                .context("Failed to send request to Anthropic API")?;
+
+            let status = response.status();
 
-        self.parse_response(ticker, company_name, response_text)
+            if status.is_success() {
+                return response
+                    .json()
+                    .await
+                    .context("Failed to parse Anthropic API response");
+            }
+
+            let retryable = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+            if retryable && retries < MAX_RETRIES {
+                let backoff = retry_after(&response).unwrap_or_else(|| {
+                    Duration::from_millis(2u64.pow(retries) * 1000)
+                });
+                warn!(
+                    "Anthropic API returned {}, backing off for {:?} ({}/{})",
+                    status,
+                    backoff,
+                    retries + 1,
+                    MAX_RETRIES
+                );
+                if let Some(stats) = stats {
+                    stats.retries.fetch_add(1, Ordering::Relaxed);
+                }
+                sleep(backoff).await;
+                retries += 1;
+                continue;
+            }
+
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Anthropic API error {}: {}", status, error_text);
+        }
     }
 
     /// Build classification prompt for Claude
@@ -164,15 +383,7 @@ Space Infrastructure Segments:
 4. **Components**: Propulsion systems, sensors, materials, avionics, spacecraft components
 
 Your Analysis Task:
-Analyze this company and provide your assessment in the following JSON format:
-
-{
-  "is_space_related": true/false,
-  "space_revenue_pct": <number 0-100>,
-  "confidence": "high/medium/low",
-  "segments": [<list of applicable segments from above>],
-  "reasoning": "<brief explanation of your assessment>"
-}
+Analyze this company and record your assessment via the classify_company tool.
 
 Guidelines:
 - is_space_related: true if ANY meaningful portion of business involves space infrastructure
@@ -190,131 +401,116 @@ Guidelines:
 - reasoning: 2-3 sentences explaining your assessment and space_revenue_pct estimate
 
 Important: Be conservative with space_revenue_pct estimates. Only assign high percentages (>50%) for clear pure-play or space-focused companies.
-
-Return ONLY the JSON object, no other text.
 "#,
         );
 
         prompt
     }
 
-    /// Parse Claude's JSON response into Classification
-    fn parse_response(
+    /// Deserialize a forced `classify_company` tool call's `input` into a `Classification`.
+    /// Since `tool_choice` pins the model to this tool, `input` already conforms to the
+    /// schema - no prose-stripping or best-effort JSON extraction needed.
+    fn parse_tool_input(
         &self,
         ticker: &str,
         company_name: &str,
-        response_text: &str,
+        tool_input: serde_json::Value,
     ) -> Result<Classification> {
-        // Extract JSON from response (in case there's extra text)
-        let start_idx = response_text.find('{');
-        let end_idx = response_text.rfind('}');
-
-        match (start_idx, end_idx) {
-            (Some(start), Some(end)) if start < end => {
-                let json_str = &response_text[start..=end];
-
-                match serde_json::from_str::<ClassificationData>(json_str) {
-                    Ok(data) => Ok(Classification {
-                        ticker: ticker.to_string(),
-                        company_name: company_name.to_string(),
-                        is_space_related: data.is_space_related,
-                        space_revenue_pct: data.space_revenue_pct,
-                        confidence: data.confidence,
-                        segments: data.segments,
-                        reasoning: data.reasoning,
-                        raw_response: response_text.to_string(),
-                    }),
-                    Err(e) => {
-                        warn!("Error parsing AI response: {}", e);
-                        warn!("Response: {}", response_text);
-
-                        Ok(Classification {
-                            ticker: ticker.to_string(),
-                            company_name: company_name.to_string(),
-                            is_space_related: false,
-                            space_revenue_pct: 0.0,
-                            confidence: "low".to_string(),
-                            segments: vec![],
-                            reasoning: format!("Error parsing AI response: {}", e),
-                            raw_response: response_text.to_string(),
-                        })
-                    }
-                }
-            }
-            _ => {
-                warn!("No JSON found in response: {}", response_text);
-
-                Ok(Classification {
-                    ticker: ticker.to_string(),
-                    company_name: company_name.to_string(),
-                    is_space_related: false,
-                    space_revenue_pct: 0.0,
-                    confidence: "low".to_string(),
-                    segments: vec![],
-                    reasoning: "No JSON found in AI response".to_string(),
-                    raw_response: response_text.to_string(),
-                })
-            }
-        }
+        let raw_response = tool_input.to_string();
+        let data: ClassificationData = serde_json::from_value(tool_input)
+            .context("classify_company tool input did not match the expected schema")?;
+
+        Ok(Classification {
+            ticker: ticker.to_string(),
+            company_name: company_name.to_string(),
+            is_space_related: data.is_space_related,
+            space_revenue_pct: data.space_revenue_pct,
+            confidence: data.confidence,
+            segments: data.segments,
+            reasoning: data.reasoning,
+            raw_response,
+        })
     }
 
-    /// Classify multiple companies in batch
+    /// Classify multiple companies concurrently, up to `concurrency` requests in flight at
+    /// once (gated by a `Semaphore` so the bound holds even across retries), serving
+    /// already-seen inputs from the on-disk cache instead of re-hitting the API. Results
+    /// are returned in the same order as `companies` despite running out of order.
     pub async fn batch_classify(
         &self,
         companies: Vec<CompanyInfo>,
+        concurrency: usize,
         verbose: bool,
-    ) -> Vec<Classification> {
-        let mut results = Vec::new();
+    ) -> (Vec<Classification>, BatchClassifyStats) {
         let total = companies.len();
+        let stats = Arc::new(BatchStats::default());
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
 
-        for (i, company) in companies.into_iter().enumerate() {
-            if verbose {
-                println!(
-                    "Classifying {}/{}: {} - {}",
-                    i + 1,
-                    total,
-                    company.ticker,
-                    company.name
-                );
-            }
+        let mut indexed_results: Vec<(usize, Classification)> = stream::iter(companies.into_iter().enumerate())
+            .map(|(i, company)| {
+                let classifier = self.clone();
+                let stats = Arc::clone(&stats);
+                let semaphore = Arc::clone(&semaphore);
+
+                async move {
+                    let _permit = semaphore
+                        .acquire_owned()
+                        .await
+                        .expect("classification semaphore should never be closed");
+
+                    if verbose {
+                        println!(
+                            "Classifying {}/{}: {} - {}",
+                            i + 1,
+                            total,
+                            company.ticker,
+                            company.name
+                        );
+                    }
+
+                    let result = classifier
+                        .classify_company_tracked(
+                            &company.ticker,
+                            &company.name,
+                            &company.description,
+                            company.context.as_deref(),
+                            Some(&stats),
+                        )
+                        .await
+                        .unwrap_or_else(|e| {
+                            warn!("Error classifying {}: {}", company.ticker, e);
+                            Classification {
+                                ticker: company.ticker.clone(),
+                                company_name: company.name.clone(),
+                                is_space_related: false,
+                                space_revenue_pct: 0.0,
+                                confidence: "low".to_string(),
+                                segments: vec![],
+                                reasoning: format!("Error: {}", e),
+                                raw_response: String::new(),
+                            }
+                        });
 
-            match self
-                .classify_company(
-                    &company.ticker,
-                    &company.name,
-                    &company.description,
-                    company.context.as_deref(),
-                )
-                .await
-            {
-                Ok(result) => {
                     if verbose {
                         println!(
-                            "  â†’ Space: {}, Revenue %: {:.0}%, Segments: {}",
+                            "  -> Space: {}, Revenue %: {:.0}%, Segments: {}",
                             result.is_space_related,
                             result.space_revenue_pct,
                             result.segments.join(", ")
                         );
                     }
-                    results.push(result);
-                }
-                Err(e) => {
-                    warn!("Error classifying {}: {}", company.ticker, e);
-                    results.push(Classification {
-                        ticker: company.ticker.clone(),
-                        company_name: company.name.clone(),
-                        is_space_related: false,
-                        space_revenue_pct: 0.0,
-                        confidence: "low".to_string(),
-                        segments: vec![],
-                        reasoning: format!("Error: {}", e),
-                        raw_response: String::new(),
-                    });
+
+                    (i, result)
                 }
-            }
-        }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        indexed_results.sort_by_key(|(i, _)| *i);
+        let results = indexed_results.into_iter().map(|(_, c)| c).collect();
 
-        results
+        (results, stats.snapshot())
     }
 }
 
@@ -330,13 +526,21 @@ pub struct CompanyInfo {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_prompt_building() {
-        let classifier = AnthropicClassifier {
+    /// A classifier backed by a throwaway temp-dir cache, named after `label` so parallel
+    /// tests don't trip over each other's cache files
+    fn test_classifier(label: &str) -> AnthropicClassifier {
+        let cache_dir = std::env::temp_dir().join(format!("ai-classifier-test-{}", label));
+        AnthropicClassifier {
             api_key: "test_key".to_string(),
             client: Client::new(),
             model: MODEL.to_string(),
-        };
+            cache: Arc::new(ClassificationCache::new(cache_dir).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_prompt_building() {
+        let classifier = test_classifier("prompt-building");
 
         let prompt = classifier.build_classification_prompt(
             "RKLB",
@@ -352,23 +556,19 @@ mod tests {
     }
 
     #[test]
-    fn test_json_parsing() {
-        let classifier = AnthropicClassifier {
-            api_key: "test_key".to_string(),
-            client: Client::new(),
-            model: MODEL.to_string(),
-        };
+    fn test_tool_input_parsing() {
+        let classifier = test_classifier("tool-input-parsing");
 
-        let response = r#"{
+        let tool_input = json!({
             "is_space_related": true,
             "space_revenue_pct": 90.0,
             "confidence": "high",
             "segments": ["Launch", "Satellites"],
             "reasoning": "Rocket Lab is a pure-play space company."
-        }"#;
+        });
 
         let result = classifier
-            .parse_response("RKLB", "Rocket Lab", response)
+            .parse_tool_input("RKLB", "Rocket Lab", tool_input)
             .unwrap();
 
         assert!(result.is_space_related);
@@ -376,4 +576,36 @@ mod tests {
         assert_eq!(result.confidence, "high");
         assert_eq!(result.segments.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_classify_company_serves_cache_hit_without_network() {
+        let classifier = test_classifier("cache-hit");
+        let key = ClassificationCache::key_for("RKLB", "Rocket Lab", "Launch provider");
+        classifier
+            .cache
+            .put(
+                &key,
+                &Classification {
+                    ticker: "RKLB".to_string(),
+                    company_name: "Rocket Lab".to_string(),
+                    is_space_related: true,
+                    space_revenue_pct: 90.0,
+                    confidence: "high".to_string(),
+                    segments: vec!["Launch".to_string()],
+                    reasoning: "Cached.".to_string(),
+                    raw_response: String::new(),
+                },
+            )
+            .unwrap();
+
+        let stats = BatchStats::default();
+        let result = classifier
+            .classify_company_tracked("RKLB", "Rocket Lab", "Launch provider", None, Some(&stats))
+            .await
+            .unwrap();
+
+        assert_eq!(result.reasoning, "Cached.");
+        assert_eq!(stats.snapshot().cache_hits, 1);
+        assert_eq!(stats.snapshot().requests_sent, 0);
+    }
 }
@@ -0,0 +1,96 @@
+// Persistent on-disk classification cache, keyed by a hash of ticker + company_name +
+// description, so unchanged inputs are never reclassified across separate `batch_classify`
+// runs (unlike `data_ingestion::cache::TickerCache`, this survives process restarts)
+
+use crate::anthropic::Classification;
+use anyhow::{Context, Result};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+/// Reads and writes one JSON file per cached `Classification`, named after the hashed key
+pub struct ClassificationCache {
+    dir: PathBuf,
+}
+
+impl ClassificationCache {
+    /// Use (and create if missing) `dir` as the cache's backing directory
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create classification cache dir {:?}", dir))?;
+        Ok(Self { dir })
+    }
+
+    /// Hash `ticker + company_name + description` into a cache key - any change to the
+    /// input invalidates the cached result
+    pub fn key_for(ticker: &str, company_name: &str, description: &str) -> String {
+        let mut hasher = DefaultHasher::new();
+        ticker.hash(&mut hasher);
+        company_name.hash(&mut hasher);
+        description.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// Return the cached classification for `key`, or `None` on a miss or unreadable entry
+    pub fn get(&self, key: &str) -> Option<Classification> {
+        let contents = std::fs::read_to_string(self.path_for(key)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    pub fn put(&self, key: &str, classification: &Classification) -> Result<()> {
+        let contents = serde_json::to_string_pretty(classification)
+            .context("Failed to serialize Classification for caching")?;
+        std::fs::write(self.path_for(key), contents)
+            .with_context(|| format!("Failed to write classification cache entry {}", key))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(ticker: &str) -> Classification {
+        Classification {
+            ticker: ticker.to_string(),
+            company_name: "Rocket Lab".to_string(),
+            is_space_related: true,
+            space_revenue_pct: 90.0,
+            confidence: "high".to_string(),
+            segments: vec!["Launch".to_string()],
+            reasoning: "Pure-play launch provider.".to_string(),
+            raw_response: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("classification-cache-test-{:016x}", {
+            let mut hasher = DefaultHasher::new();
+            "test_cache_roundtrip".hash(&mut hasher);
+            hasher.finish()
+        }));
+        let cache = ClassificationCache::new(&dir).unwrap();
+
+        let key = ClassificationCache::key_for("RKLB", "Rocket Lab", "Launch provider");
+        assert!(cache.get(&key).is_none());
+
+        cache.put(&key, &sample("RKLB")).unwrap();
+        let cached = cache.get(&key).unwrap();
+        assert_eq!(cached.ticker, "RKLB");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_key_changes_with_description() {
+        let a = ClassificationCache::key_for("RKLB", "Rocket Lab", "Launch provider");
+        let b = ClassificationCache::key_for("RKLB", "Rocket Lab", "Launch provider v2");
+        assert_ne!(a, b);
+    }
+}
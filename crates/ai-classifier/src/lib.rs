@@ -0,0 +1,11 @@
+// AI classifier crate - Anthropic-backed space-infrastructure classification
+
+pub mod anthropic;
+pub mod cache;
+pub mod overrides;
+
+pub use anthropic::{
+    AnthropicClassifier, BatchClassifyStats, Classification, CompanyInfo, DEFAULT_CONCURRENCY,
+};
+pub use cache::ClassificationCache;
+pub use overrides::{ClassificationOverride, OverrideTable};
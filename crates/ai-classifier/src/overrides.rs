@@ -0,0 +1,140 @@
+// Human override layer for AI classifications. Loads a curated ticker -> override table and
+// applies it as a final pass over `batch_classify` results ("override the upstream data once
+// after load").
+
+use crate::anthropic::Classification;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A curated correction for one ticker. Every field is optional - only the fields present
+/// replace the AI's estimate, everything else is left as `batch_classify` produced it.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ClassificationOverride {
+    pub is_space_related: Option<bool>,
+    pub space_revenue_pct: Option<f32>,
+    pub segments: Option<Vec<String>>,
+    /// Why this ticker needed a manual correction - prefixed onto `reasoning` so the
+    /// override is visible downstream (newsletter, CLI output) without a separate field
+    pub note: String,
+}
+
+/// Ticker -> curated correction, loaded once and applied to every classification batch
+#[derive(Debug, Default, Clone)]
+pub struct OverrideTable {
+    overrides: HashMap<String, ClassificationOverride>,
+}
+
+impl OverrideTable {
+    /// Load a JSON object of `{ "TICKER": { ... }, ... }` from `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read override table {:?}", path))?;
+        let overrides: HashMap<String, ClassificationOverride> = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse override table {:?}", path))?;
+
+        Ok(Self { overrides })
+    }
+
+    /// Apply curated corrections to `classifications` in place, returning the tickers that
+    /// were actually overridden so callers can surface which results are curated rather
+    /// than raw AI output
+    pub fn apply(&self, classifications: &mut [Classification]) -> Vec<String> {
+        let mut overridden = Vec::new();
+
+        for classification in classifications.iter_mut() {
+            let Some(over) = self.overrides.get(&classification.ticker) else {
+                continue;
+            };
+
+            if let Some(is_space_related) = over.is_space_related {
+                classification.is_space_related = is_space_related;
+            }
+            if let Some(space_revenue_pct) = over.space_revenue_pct {
+                classification.space_revenue_pct = space_revenue_pct;
+            }
+            if let Some(segments) = &over.segments {
+                classification.segments = segments.clone();
+            }
+
+            classification.confidence = "high".to_string();
+            classification.reasoning = format!(
+                "[Manual override: {}] {}",
+                over.note, classification.reasoning
+            );
+
+            overridden.push(classification.ticker.clone());
+        }
+
+        overridden
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classification(ticker: &str) -> Classification {
+        Classification {
+            ticker: ticker.to_string(),
+            company_name: "Some Co".to_string(),
+            is_space_related: false,
+            space_revenue_pct: 5.0,
+            confidence: "low".to_string(),
+            segments: vec![],
+            reasoning: "AI couldn't find much information.".to_string(),
+            raw_response: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_apply_overrides_only_named_tickers() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "RKLB".to_string(),
+            ClassificationOverride {
+                is_space_related: Some(true),
+                space_revenue_pct: Some(95.0),
+                segments: Some(vec!["Launch".to_string()]),
+                note: "Confirmed pure-play via 10-K".to_string(),
+            },
+        );
+        let table = OverrideTable { overrides };
+
+        let mut classifications = vec![classification("RKLB"), classification("UNRELATED")];
+        let touched = table.apply(&mut classifications);
+
+        assert_eq!(touched, vec!["RKLB".to_string()]);
+        assert!(classifications[0].is_space_related);
+        assert_eq!(classifications[0].space_revenue_pct, 95.0);
+        assert_eq!(classifications[0].confidence, "high");
+        assert!(classifications[0].reasoning.starts_with("[Manual override: Confirmed pure-play via 10-K]"));
+
+        assert!(!classifications[1].is_space_related);
+        assert_eq!(classifications[1].reasoning, "AI couldn't find much information.");
+    }
+
+    #[test]
+    fn test_partial_override_leaves_unspecified_fields_alone() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "RKLB".to_string(),
+            ClassificationOverride {
+                is_space_related: None,
+                space_revenue_pct: Some(42.0),
+                segments: None,
+                note: "Adjusting estimate only".to_string(),
+            },
+        );
+        let table = OverrideTable { overrides };
+
+        let mut classifications = vec![classification("RKLB")];
+        table.apply(&mut classifications);
+
+        assert!(!classifications[0].is_space_related);
+        assert_eq!(classifications[0].space_revenue_pct, 42.0);
+        assert!(classifications[0].segments.is_empty());
+    }
+}
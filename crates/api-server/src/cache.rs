@@ -0,0 +1,80 @@
+// Concurrent TTL cache for hot, infrequently-changing reads (index metadata, composition)
+
+use dashmap::DashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+/// Hit/miss counters for a `TtlCache`, snapshotted at a point in time
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+}
+
+/// A concurrent, TTL-expiring cache. Reads that land within `ttl` of the last `insert`
+/// are served from memory; everything else is a miss the caller should fall through to
+/// the database for.
+pub struct TtlCache<K, V> {
+    entries: DashMap<K, CacheEntry<V>>,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: DashMap::new(),
+            ttl,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Return a fresh cached value for `key`, or `None` on a miss or expired entry
+    pub fn get(&self, key: &K) -> Option<V> {
+        if let Some(entry) = self.entries.get(key) {
+            if entry.inserted_at.elapsed() < self.ttl {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Some(entry.value.clone());
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop a single cached entry; call after a write lands fresh data for `key`
+    pub fn invalidate(&self, key: &K) {
+        self.entries.remove(key);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: self.entries.len(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Concrete scheduled jobs that keep index data fresh without external cron.
+//
+// These delegate to the same command implementations the CLI daemon uses
+// (`cli::commands::data::update_fundamentals`, `cli::commands::index::rebalance_index`) rather
+// than re-deriving the fetch/rebalance logic here, so the two long-running entry points into
+// this codebase can't silently drift apart.
+
+use crate::state::AppState;
+use anyhow::Result;
+use async_trait::async_trait;
+use scheduler::Job;
+use std::time::Duration;
+use tracing::info;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const QUARTER: Duration = Duration::from_secs(91 * 24 * 60 * 60);
+
+/// Concurrency used for the nightly refresh - matches the CLI daemon's own default
+/// (`future-indices-cli daemon`'s `--concurrency`) since nothing here needs it tuned per-run.
+const FUNDAMENTALS_CONCURRENCY: usize = 5;
+
+/// Nightly refresh of every tracked company's fundamentals
+pub struct UpdateFundamentalsJob {
+    pub state: AppState,
+}
+
+#[async_trait]
+impl Job for UpdateFundamentalsJob {
+    fn name(&self) -> String {
+        "update_fundamentals".to_string()
+    }
+
+    fn interval(&self) -> Duration {
+        DAY
+    }
+
+    async fn run(&self) -> Result<()> {
+        let summary = cli::commands::data::update_fundamentals(FUNDAMENTALS_CONCURRENCY).await?;
+        info!(
+            "Scheduled job: refreshed fundamentals for all tracked companies \
+             (succeeded={}, failed={}, skipped={}, elapsed={:.1}s, median_latency={}ms)",
+            summary.succeeded,
+            summary.failed,
+            summary.skipped,
+            summary.elapsed.as_secs_f64(),
+            summary.median_latency.as_millis(),
+        );
+        Ok(())
+    }
+}
+
+/// Quarterly rebalance of a single index
+pub struct RebalanceJob {
+    pub state: AppState,
+    pub index_name: String,
+}
+
+#[async_trait]
+impl Job for RebalanceJob {
+    fn name(&self) -> String {
+        format!("rebalance:{}", self.index_name)
+    }
+
+    fn interval(&self) -> Duration {
+        QUARTER
+    }
+
+    async fn run(&self) -> Result<()> {
+        cli::commands::index::rebalance_index(&self.index_name, &current_quarter(), false).await?;
+        self.state.invalidate_index_cache(&self.index_name);
+        info!("Scheduled job: rebalanced {}", self.index_name);
+        Ok(())
+    }
+}
+
+/// The current calendar quarter as a `QN-YYYY` label, matching the format
+/// `rebalance_index` expects (same definition as `cli::commands::daemon::current_quarter`).
+fn current_quarter() -> String {
+    let now = chrono::Utc::now();
+    let quarter = (chrono::Datelike::month(&now) - 1) / 3 + 1;
+    format!("Q{}-{}", quarter, chrono::Datelike::year(&now))
+}
@@ -1,16 +1,27 @@
 // API server main entry point
 
-use axum::{routing::get, Json, Router};
+use axum::{
+    extract::State,
+    routing::{get, post},
+    Json, Router,
+};
 use serde::Serialize;
 use std::net::SocketAddr;
 use tower_http::cors::CorsLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod cache;
+mod jobs;
 mod models;
 mod routes;
 mod state;
 
+use cache::CacheStats;
+use jobs::{RebalanceJob, UpdateFundamentalsJob};
+use scheduler::JobStatus;
 use state::AppState;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Serialize)]
 struct HealthResponse {
@@ -25,6 +36,27 @@ async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+#[derive(Serialize)]
+struct CacheStatsResponse {
+    metadata_cache: CacheStats,
+    composition_cache: CacheStats,
+}
+
+/// GET /api/cache/stats
+/// Hit/miss counters for the index metadata/composition TTL caches
+async fn cache_stats(State(state): State<AppState>) -> Json<CacheStatsResponse> {
+    Json(CacheStatsResponse {
+        metadata_cache: state.metadata_cache.stats(),
+        composition_cache: state.composition_cache.stats(),
+    })
+}
+
+/// GET /api/jobs
+/// Next-run time and last-run status for every recurring job the server schedules
+async fn job_status(State(state): State<AppState>) -> Json<Vec<JobStatus>> {
+    Json(state.scheduler.status())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize tracing
@@ -49,9 +81,36 @@ async fn main() -> anyhow::Result<()> {
 
     let state = AppState::new(pool);
 
+    // Keep indices fresh without external cron: queue the recurring jobs and let the
+    // scheduler run them for the lifetime of the server
+    for index_name in ["SPACEINFRA", "AIINFRA"] {
+        state
+            .scheduler
+            .schedule(
+                Arc::new(RebalanceJob {
+                    state: state.clone(),
+                    index_name: index_name.to_string(),
+                }),
+                Duration::ZERO,
+            )
+            .await;
+    }
+    state
+        .scheduler
+        .schedule(
+            Arc::new(UpdateFundamentalsJob {
+                state: state.clone(),
+            }),
+            Duration::ZERO,
+        )
+        .await;
+    tokio::spawn(state.scheduler.clone().run());
+
     // Build router
     let app = Router::new()
         .route("/health", get(health_check))
+        .route("/api/cache/stats", get(cache_stats))
+        .route("/api/jobs", get(job_status))
         // Index routes
         .route("/api/indices", get(routes::indices::list_indices))
         .route("/api/indices/:name", get(routes::indices::get_index))
@@ -59,10 +118,24 @@ async fn main() -> anyhow::Result<()> {
             "/api/indices/:name/composition",
             get(routes::indices::get_composition),
         )
+        .route(
+            "/api/indices/:name/screen",
+            get(routes::indices::screen_composition),
+        )
         .route(
             "/api/indices/:name/performance",
             get(routes::indices::get_performance),
         )
+        .route(
+            "/api/indices/:name/backtest",
+            post(routes::indices::run_backtest),
+        )
+        // Third-party-friendly, field-stable ticker routes
+        .route("/api/v1/tickers", get(routes::indices::list_tickers))
+        .route(
+            "/api/v1/index/:name/constituents",
+            get(routes::indices::get_constituents),
+        )
         .with_state(state)
         .layer(CorsLayer::permissive());
 
@@ -47,11 +47,41 @@ pub struct PerformanceResponse {
     pub index_name: String,
     pub from_date: NaiveDate,
     pub to_date: NaiveDate,
+    pub return_type: String,
     pub data: Vec<PerformanceData>,
     pub total_return: f64,
     pub annualized_return: f64,
     pub volatility: f64,
     pub sharpe_ratio: Option<f64>,
+    pub max_drawdown: f64,
+    pub sortino_ratio: Option<f64>,
+    pub beta: Option<f64>,
+    pub alpha: Option<f64>,
+    pub tracking_error: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexScreenResponse {
+    pub index_name: String,
+    pub constituents: Vec<ConstituentInfo>,
+    pub total_weight: f64,
+    pub num_companies: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BacktestResponse {
+    pub index_name: String,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub algorithm: String,
+    pub rebalance_frequency: String,
+    pub data: Vec<PerformanceData>,
+    pub total_return: f64,
+    pub annualized_return: f64,
+    pub volatility: f64,
+    pub sharpe_ratio: Option<f64>,
+    pub max_drawdown: f64,
+    pub sortino_ratio: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -59,3 +89,23 @@ pub struct ErrorResponse {
     pub error: String,
     pub message: String,
 }
+
+/// A single constituent in the flat, field-stable shape third-party aggregators expect
+/// (modeled on the CoinGecko-style `/coingecko/tickers` format)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TickerInfo {
+    pub ticker_id: String,
+    pub base_currency: String,
+    pub target_currency: String,
+    pub last_price: Option<f64>,
+    pub weight: f64,
+    pub change_24h_pct: Option<f64>,
+    pub market_cap: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConstituentsResponse {
+    pub index_name: String,
+    pub as_of_date: NaiveDate,
+    pub tickers: Vec<TickerInfo>,
+}
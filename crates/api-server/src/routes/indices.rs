@@ -5,14 +5,21 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use chrono::NaiveDate;
+use chrono::{Months, NaiveDate};
+use database::IndexRegistryEntry;
+use index_engine::{
+    BacktestAlgorithm, CompanyFundamentalPoint, ConstituentHistory, RebalanceFrequency,
+    WeightingAlgorithm,
+};
 use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use tracing::error;
 
 use crate::{
     models::{
-        ConstituentInfo, ErrorResponse, IndexCompositionResponse, IndexInfo, PerformanceData,
-        PerformanceResponse,
+        BacktestResponse, ConstituentInfo, ConstituentsResponse, ErrorResponse,
+        IndexCompositionResponse, IndexInfo, IndexScreenResponse, PerformanceData,
+        PerformanceResponse, TickerInfo,
     },
     state::AppState,
 };
@@ -24,6 +31,238 @@ use database;
 pub struct PerformanceQuery {
     pub from: Option<String>,
     pub to: Option<String>,
+    pub return_type: Option<String>,
+    pub risk_free_rate: Option<f64>,
+}
+
+/// Number of most-recent fundamental rows to pull per ticker when reconstructing
+/// a price history for dividend-yield calculation
+const PRICE_HISTORY_LIMIT: i64 = 5000;
+
+/// Index name the benchmark (S&P 500) daily series is stored under
+const BENCHMARK_INDEX_NAME: &str = "SP500";
+
+/// Max drawdown over an index-value series: the largest peak-to-trough decline
+fn max_drawdown(values: &[f64]) -> f64 {
+    let mut peak = values[0];
+    let mut max_dd = 0.0_f64;
+
+    for &value in values {
+        peak = peak.max(value);
+        let drawdown = (peak - value) / peak;
+        max_dd = max_dd.max(drawdown);
+    }
+
+    max_dd * 100.0
+}
+
+/// Sortino ratio: like Sharpe, but only penalizing downside deviation below the
+/// target/MAR (default 0)
+fn sortino_ratio(daily_returns: &[f64], annualized_return: f64, risk_free_rate: f64, target: f64) -> Option<f64> {
+    let downside: Vec<f64> = daily_returns
+        .iter()
+        .filter(|&&r| r < target)
+        .map(|r| (r - target).powi(2))
+        .collect();
+
+    if downside.is_empty() {
+        return None;
+    }
+
+    let downside_deviation =
+        (downside.iter().sum::<f64>() / downside.len() as f64).sqrt() * 252.0_f64.sqrt() * 100.0;
+
+    if downside_deviation > 0.0 {
+        Some((annualized_return - risk_free_rate) / downside_deviation)
+    } else {
+        None
+    }
+}
+
+/// Beta/alpha/tracking-error of the index against a benchmark daily-return series,
+/// aligned by date. Requires at least two overlapping points.
+fn benchmark_risk_metrics(
+    index_returns_by_date: &HashMap<NaiveDate, f64>,
+    benchmark_returns_by_date: &HashMap<NaiveDate, f64>,
+    annualized_index_return: f64,
+    annualized_benchmark_return: f64,
+    risk_free_rate: f64,
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    let mut index_returns = Vec::new();
+    let mut benchmark_returns = Vec::new();
+
+    for (date, &index_return) in index_returns_by_date {
+        if let Some(&benchmark_return) = benchmark_returns_by_date.get(date) {
+            index_returns.push(index_return);
+            benchmark_returns.push(benchmark_return);
+        }
+    }
+
+    if index_returns.len() < 2 {
+        return (None, None, None);
+    }
+
+    let n = index_returns.len() as f64;
+    let index_mean = index_returns.iter().sum::<f64>() / n;
+    let benchmark_mean = benchmark_returns.iter().sum::<f64>() / n;
+
+    let covariance = index_returns
+        .iter()
+        .zip(benchmark_returns.iter())
+        .map(|(i, b)| (i - index_mean) * (b - benchmark_mean))
+        .sum::<f64>()
+        / n;
+
+    let benchmark_variance = benchmark_returns
+        .iter()
+        .map(|b| (b - benchmark_mean).powi(2))
+        .sum::<f64>()
+        / n;
+
+    if benchmark_variance <= 0.0 {
+        return (None, None, None);
+    }
+
+    let beta = covariance / benchmark_variance;
+    let alpha =
+        annualized_index_return - risk_free_rate - beta * (annualized_benchmark_return - risk_free_rate);
+
+    let tracking_diffs: Vec<f64> = index_returns
+        .iter()
+        .zip(benchmark_returns.iter())
+        .map(|(i, b)| i - b)
+        .collect();
+    let tracking_mean = tracking_diffs.iter().sum::<f64>() / n;
+    let tracking_variance = tracking_diffs
+        .iter()
+        .map(|d| (d - tracking_mean).powi(2))
+        .sum::<f64>()
+        / n;
+    let tracking_error = tracking_variance.sqrt() * 252.0_f64.sqrt() * 100.0;
+
+    (Some(beta), Some(alpha), Some(tracking_error))
+}
+
+/// Compute the total-return dividend yield contribution for each ex-date in
+/// `[from_date, to_date]`, weighted by each constituent's current index weight.
+///
+/// `amount_per_share` and the ex-date price are both recorded in the same historical share
+/// basis, so `amount / raw_price` is already split-invariant - no separate split adjustment
+/// is needed (or correct: scaling only the denominator by a later split's ratio would inflate
+/// the yield by that split's factor).
+async fn compute_dividend_yields(
+    state: &AppState,
+    index_name: &str,
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> anyhow::Result<HashMap<NaiveDate, f64>> {
+    let compositions = database::get_index_composition_with_companies(&state.db, index_name).await?;
+    let tickers: Vec<String> = compositions.iter().map(|c| c.ticker.clone()).collect();
+
+    if tickers.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let dividends = database::get_dividends_for_tickers(&state.db, &tickers, from_date, to_date).await?;
+
+    let mut yields_by_date: HashMap<NaiveDate, f64> = HashMap::new();
+
+    for composition in &compositions {
+        let Some(company) = database::get_company_by_ticker(&state.db, &composition.ticker).await? else {
+            continue;
+        };
+
+        let fundamentals =
+            database::get_fundamentals_by_company(&state.db, company.id, PRICE_HISTORY_LIMIT).await?;
+        let prices_by_date: BTreeMap<NaiveDate, f32> = fundamentals
+            .into_iter()
+            .filter_map(|f| f.price.map(|p| (f.date, p)))
+            .collect();
+
+        for dividend in dividends.iter().filter(|d| d.ticker == composition.ticker) {
+            let Some(amount) = dividend.amount_per_share else {
+                continue;
+            };
+
+            let Some((_, &raw_price)) = prices_by_date.range(..=dividend.ex_date).next_back() else {
+                continue;
+            };
+
+            if raw_price <= 0.0 {
+                continue;
+            }
+
+            let contribution = composition.weight * (amount as f64 / raw_price as f64);
+            *yields_by_date.entry(dividend.ex_date).or_insert(0.0) += contribution;
+        }
+    }
+
+    Ok(yields_by_date)
+}
+
+/// Compute the next scheduled rebalance date from a definition's cadence,
+/// anchored on the last rebalance if one has happened yet, else inception.
+fn next_rebalance_date(definition: &IndexRegistryEntry, last_rebalance: Option<NaiveDate>) -> Option<NaiveDate> {
+    let anchor = last_rebalance.unwrap_or(definition.inception_date);
+    anchor.checked_add_months(Months::new(definition.rebalance_frequency_months as u32))
+}
+
+/// Build an `IndexInfo` from a registry definition and optional live metadata
+fn index_info_from_definition(
+    definition: IndexRegistryEntry,
+    metadata: Option<database::IndexMetadata>,
+) -> IndexInfo {
+    let last_rebalance = metadata.as_ref().and_then(|m| m.last_rebalance);
+    let next_rebalance = next_rebalance_date(&definition, last_rebalance);
+
+    IndexInfo {
+        name: definition.index_name,
+        display_name: definition.display_name,
+        description: definition.description,
+        num_constituents: metadata.as_ref().map(|m| m.num_constituents).unwrap_or(0),
+        total_market_cap: metadata.as_ref().map(|m| m.total_market_cap).unwrap_or(0.0),
+        last_rebalance,
+        next_rebalance,
+        inception_date: definition.inception_date,
+    }
+}
+
+/// Fetch index metadata through the TTL cache, falling back to the database on a miss
+/// or expiry and populating the cache with the fresh result.
+async fn cached_index_metadata(
+    state: &AppState,
+    index_name: &str,
+) -> anyhow::Result<Option<database::IndexMetadata>> {
+    if let Some(cached) = state.metadata_cache.get(&index_name.to_string()) {
+        return Ok(Some(cached));
+    }
+
+    let metadata = database::get_index_metadata(&state.db, index_name).await?;
+    if let Some(ref m) = metadata {
+        state
+            .metadata_cache
+            .insert(index_name.to_string(), m.clone());
+    }
+
+    Ok(metadata)
+}
+
+/// Fetch an index's current composition through the TTL cache, falling back to the
+/// database on a miss or expiry and populating the cache with the fresh result.
+async fn cached_index_composition(
+    state: &AppState,
+    index_name: &str,
+) -> anyhow::Result<Vec<database::CompositionWithCompany>> {
+    if let Some(cached) = state.composition_cache.get(&index_name.to_string()) {
+        return Ok(cached);
+    }
+
+    let compositions = database::get_index_composition_with_companies(&state.db, index_name).await?;
+    state
+        .composition_cache
+        .insert(index_name.to_string(), compositions.clone());
+
+    Ok(compositions)
 }
 
 /// GET /api/indices
@@ -31,56 +270,29 @@ pub struct PerformanceQuery {
 pub async fn list_indices(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<IndexInfo>>, (StatusCode, Json<ErrorResponse>)> {
-    let mut indices = Vec::new();
+    let definitions = database::get_all_index_definitions(&state.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to fetch index registry: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to fetch index registry".to_string(),
+                }),
+            )
+        })?;
 
-    // Query metadata for known indices
-    for index_name in &["SPACEINFRA", "AIINFRA"] {
-        match database::get_index_metadata(&state.db, index_name).await {
-            Ok(Some(metadata)) => {
-                let (display_name, description, inception_date, next_rebalance) = match *index_name {
-                    "SPACEINFRA" => (
-                        "Space Infrastructure Index",
-                        "Tracks companies in the space infrastructure industry including launch, satellites, ground systems, and components.",
-                        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
-                        Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                    ),
-                    "AIINFRA" => (
-                        "AI Infrastructure Index",
-                        "Tracks companies building the infrastructure for artificial intelligence.",
-                        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-                        Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                    ),
-                    _ => continue,
-                };
+    let mut indices = Vec::new();
 
-                indices.push(IndexInfo {
-                    name: metadata.index_name,
-                    display_name: display_name.to_string(),
-                    description: description.to_string(),
-                    num_constituents: metadata.num_constituents,
-                    total_market_cap: metadata.total_market_cap,
-                    last_rebalance: metadata.last_rebalance,
-                    next_rebalance,
-                    inception_date,
-                });
-            }
-            Ok(None) => {
-                // Index exists but has no data yet, return placeholder
-                if *index_name == "AIINFRA" {
-                    indices.push(IndexInfo {
-                        name: "AIINFRA".to_string(),
-                        display_name: "AI Infrastructure Index".to_string(),
-                        description: "Tracks companies building the infrastructure for artificial intelligence.".to_string(),
-                        num_constituents: 0,
-                        total_market_cap: 0.0,
-                        last_rebalance: None,
-                        next_rebalance: Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                        inception_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-                    });
-                }
-            }
+    for definition in definitions {
+        match cached_index_metadata(&state, &definition.index_name).await {
+            Ok(metadata) => indices.push(index_info_from_definition(definition, metadata)),
             Err(e) => {
-                error!("Failed to fetch metadata for {}: {:?}", index_name, e);
+                error!(
+                    "Failed to fetch metadata for {}: {:?}",
+                    definition.index_name, e
+                );
             }
         }
     }
@@ -96,77 +308,30 @@ pub async fn get_index(
 ) -> Result<Json<IndexInfo>, (StatusCode, Json<ErrorResponse>)> {
     let index_name_upper = name.to_uppercase();
 
-    // Validate index name
-    if !["SPACEINFRA", "AIINFRA"].contains(&index_name_upper.as_str()) {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "NOT_FOUND".to_string(),
-                message: format!("Index '{}' not found", name),
-            }),
-        ));
-    }
-
-    match database::get_index_metadata(&state.db, &index_name_upper).await {
-        Ok(Some(metadata)) => {
-            let (display_name, description, inception_date, next_rebalance) = match index_name_upper.as_str() {
-                "SPACEINFRA" => (
-                    "Space Infrastructure Index",
-                    "Tracks companies in the space infrastructure industry including launch, satellites, ground systems, and components.",
-                    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
-                    Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                ),
-                "AIINFRA" => (
-                    "AI Infrastructure Index",
-                    "Tracks companies building the infrastructure for artificial intelligence.",
-                    NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-                    Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                ),
-                _ => return Err((
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse {
-                        error: "NOT_FOUND".to_string(),
-                        message: format!("Index '{}' not found", name),
-                    }),
-                )),
-            };
+    let definition = database::get_index_definition(&state.db, &index_name_upper)
+        .await
+        .map_err(|e| {
+            error!("Database error fetching index definition {}: {:?}", name, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to fetch index data".to_string(),
+                }),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "NOT_FOUND".to_string(),
+                    message: format!("Index '{}' not found", name),
+                }),
+            )
+        })?;
 
-            Ok(Json(IndexInfo {
-                name: metadata.index_name,
-                display_name: display_name.to_string(),
-                description: description.to_string(),
-                num_constituents: metadata.num_constituents,
-                total_market_cap: metadata.total_market_cap,
-                last_rebalance: metadata.last_rebalance,
-                next_rebalance,
-                inception_date,
-            }))
-        }
-        Ok(None) => {
-            // Index has no composition data yet
-            if index_name_upper == "AIINFRA" {
-                Ok(Json(IndexInfo {
-                    name: "AIINFRA".to_string(),
-                    display_name: "AI Infrastructure Index".to_string(),
-                    description:
-                        "Tracks companies building the infrastructure for artificial intelligence."
-                            .to_string(),
-                    num_constituents: 0,
-                    total_market_cap: 0.0,
-                    last_rebalance: None,
-                    next_rebalance: Some(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()),
-                    inception_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-                }))
-            } else {
-                Err((
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse {
-                        error: "NOT_FOUND".to_string(),
-                        message: format!("Index '{}' has no data", name),
-                    }),
-                ))
-            }
-        }
+    match cached_index_metadata(&state, &index_name_upper).await {
+        Ok(metadata) => Ok(Json(index_info_from_definition(definition, metadata))),
         Err(e) => {
             error!("Database error fetching index {}: {:?}", name, e);
             Err((
@@ -189,18 +354,10 @@ pub async fn get_composition(
     let index_name_upper = name.to_uppercase();
 
     // Validate index name
-    if !["SPACEINFRA", "AIINFRA"].contains(&index_name_upper.as_str()) {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse {
-                error: "NOT_FOUND".to_string(),
-                message: format!("Index '{}' not found", name),
-            }),
-        ));
-    }
+    validate_index_exists(&state, &name, &index_name_upper).await?;
 
     // Fetch composition with company details
-    match database::get_index_composition_with_companies(&state.db, &index_name_upper).await {
+    match cached_index_composition(&state, &index_name_upper).await {
         Ok(compositions) => {
             if compositions.is_empty() {
                 return Err((
@@ -255,17 +412,130 @@ pub async fn get_composition(
     }
 }
 
-/// GET /api/indices/:name/performance
-/// Get historical performance data
-pub async fn get_performance(
+#[derive(Debug, Deserialize)]
+pub struct ScreenQuery {
+    pub min_weight: Option<f64>,
+    pub max_weight: Option<f64>,
+    pub min_market_cap: Option<i64>,
+    pub min_space_revenue_pct: Option<f32>,
+    pub segment: Option<String>,
+    pub sort_by: Option<String>,
+    pub order: Option<String>,
+}
+
+/// GET /api/indices/:name/screen
+/// Screen the current composition with composable weight/market-cap/segment filters
+pub async fn screen_composition(
     State(state): State<AppState>,
     Path(name): Path<String>,
-    Query(query): Query<PerformanceQuery>,
-) -> Result<Json<PerformanceResponse>, (StatusCode, Json<ErrorResponse>)> {
+    Query(query): Query<ScreenQuery>,
+) -> Result<Json<IndexScreenResponse>, (StatusCode, Json<ErrorResponse>)> {
     let index_name_upper = name.to_uppercase();
 
     // Validate index name
-    if !["SPACEINFRA", "AIINFRA"].contains(&index_name_upper.as_str()) {
+    validate_index_exists(&state, &name, &index_name_upper).await?;
+
+    let filter = database::CompositionScreenFilter {
+        min_weight: query.min_weight,
+        max_weight: query.max_weight,
+        min_market_cap: query.min_market_cap,
+        min_space_revenue_pct: query.min_space_revenue_pct,
+        segment: query.segment,
+        sort_by: query.sort_by,
+        order: query.order,
+    };
+
+    match database::get_index_composition_screened(&state.db, &index_name_upper, &filter).await {
+        Ok(compositions) => {
+            let total_weight: f64 = compositions.iter().map(|c| c.weight).sum();
+            let num_companies = compositions.len() as i32;
+
+            let constituents: Vec<ConstituentInfo> = compositions
+                .into_iter()
+                .map(|c| ConstituentInfo {
+                    ticker: c.ticker,
+                    company_name: c.company_name,
+                    weight: c.weight,
+                    market_cap: c.market_cap,
+                    space_revenue_pct: c.space_score.map(|s| (s * 100.0) as f32),
+                    segments: c.segments,
+                })
+                .collect();
+
+            Ok(Json(IndexScreenResponse {
+                index_name: index_name_upper,
+                constituents,
+                total_weight,
+                num_companies,
+            }))
+        }
+        Err(e) => {
+            error!("Database error screening composition for {}: {:?}", name, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to screen composition data".to_string(),
+                }),
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestAlgorithmChoice {
+    MarketCap,
+    EqualWeight,
+    ScoreTilted,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BacktestRequest {
+    pub from: String,
+    pub to: String,
+    pub algorithm: BacktestAlgorithmChoice,
+    pub rebalance_frequency: String,
+    pub max_position_size: Option<f32>,
+    pub min_position_size: Option<f32>,
+    pub risk_free_rate: Option<f64>,
+}
+
+fn bad_request(message: impl Into<String>) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse {
+            error: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }),
+    )
+}
+
+/// Confirm `index_name_upper` is a registered index before a handler does any further work,
+/// looking it up in the DB-backed `index_registry` (chunk0-1) rather than a hardcoded list -
+/// so an index added purely via the registry is reachable from every endpoint.
+async fn validate_index_exists(
+    state: &AppState,
+    name: &str,
+    index_name_upper: &str,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let definition = database::get_index_definition(&state.db, index_name_upper)
+        .await
+        .map_err(|e| {
+            error!(
+                "Database error fetching index definition {}: {:?}",
+                name, e
+            );
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to fetch index data".to_string(),
+                }),
+            )
+        })?;
+
+    if definition.is_none() {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ErrorResponse {
@@ -275,6 +545,237 @@ pub async fn get_performance(
         ));
     }
 
+    Ok(())
+}
+
+fn parse_rebalance_frequency(raw: &str) -> Result<RebalanceFrequency, (StatusCode, Json<ErrorResponse>)> {
+    match raw {
+        "monthly" => Ok(RebalanceFrequency::Monthly),
+        "quarterly" => Ok(RebalanceFrequency::Quarterly),
+        "annually" => Ok(RebalanceFrequency::Annually),
+        _ => Err(bad_request(
+            "rebalance_frequency must be 'monthly', 'quarterly', or 'annually'",
+        )),
+    }
+}
+
+fn build_backtest_algorithm(
+    choice: &BacktestAlgorithmChoice,
+    max_position_size: f32,
+    min_position_size: f32,
+) -> anyhow::Result<BacktestAlgorithm> {
+    match choice {
+        BacktestAlgorithmChoice::MarketCap => Ok(BacktestAlgorithm::ThreeFactor(
+            WeightingAlgorithm::new(0.0, 1.0, 0.0, max_position_size, min_position_size)?,
+        )),
+        BacktestAlgorithmChoice::ScoreTilted => Ok(BacktestAlgorithm::ThreeFactor(
+            WeightingAlgorithm::new(0.4, 0.3, 0.3, max_position_size, min_position_size)?,
+        )),
+        BacktestAlgorithmChoice::EqualWeight => Ok(BacktestAlgorithm::EqualWeight {
+            max_position_size,
+            min_position_size,
+        }),
+    }
+}
+
+/// Build the backtest universe (fundamentals + price history per constituent, restricted
+/// to `[from_date, to_date]`) from the index's current composition, and the sorted set of
+/// dates on which any constituent has a fundamentals snapshot in that window.
+async fn load_backtest_universe(
+    state: &AppState,
+    index_name: &str,
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> anyhow::Result<(Vec<ConstituentHistory>, Vec<NaiveDate>)> {
+    let compositions = database::get_index_composition_with_companies(&state.db, index_name).await?;
+
+    let mut universe = Vec::with_capacity(compositions.len());
+    let mut dates: BTreeSet<NaiveDate> = BTreeSet::new();
+
+    for composition in compositions {
+        let Some(company) = database::get_company_by_ticker(&state.db, &composition.ticker).await?
+        else {
+            continue;
+        };
+
+        let fundamentals =
+            database::get_fundamentals_by_company(&state.db, company.id, PRICE_HISTORY_LIMIT).await?;
+
+        let mut fundamentals_by_date = BTreeMap::new();
+        let mut prices = BTreeMap::new();
+
+        for f in fundamentals {
+            if f.date < from_date || f.date > to_date {
+                continue;
+            }
+
+            if let Some(price) = f.price {
+                prices.insert(f.date, price as f64);
+            }
+
+            if let (Some(market_cap), Some(revenue_growth)) = (f.market_cap, f.revenue_growth_yoy) {
+                fundamentals_by_date.insert(
+                    f.date,
+                    CompanyFundamentalPoint {
+                        market_cap: market_cap as f64,
+                        space_revenue_pct: company.space_score.unwrap_or(0.0) * 100.0,
+                        revenue_growth_rate: revenue_growth,
+                    },
+                );
+                dates.insert(f.date);
+            }
+        }
+
+        universe.push(ConstituentHistory {
+            ticker: composition.ticker,
+            name: composition.company_name,
+            segments: composition.segments.map(|s| s.join(",")),
+            fundamentals: fundamentals_by_date,
+            prices,
+        });
+    }
+
+    Ok((universe, dates.into_iter().collect()))
+}
+
+/// POST /api/indices/:name/backtest
+/// Reconstruct a hypothetical index value series over a historical window under a
+/// chosen weighting methodology, returning the same return, volatility, Sharpe, drawdown and
+/// Sortino statistics as `/performance` (no benchmark-relative beta/alpha/tracking error)
+pub async fn run_backtest(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Json(request): Json<BacktestRequest>,
+) -> Result<Json<BacktestResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let index_name_upper = name.to_uppercase();
+
+    validate_index_exists(&state, &name, &index_name_upper).await?;
+
+    let from_date = NaiveDate::parse_from_str(&request.from, "%Y-%m-%d")
+        .map_err(|_| bad_request("from must be a valid date in YYYY-MM-DD format"))?;
+    let to_date = NaiveDate::parse_from_str(&request.to, "%Y-%m-%d")
+        .map_err(|_| bad_request("to must be a valid date in YYYY-MM-DD format"))?;
+    if from_date >= to_date {
+        return Err(bad_request("from must be before to"));
+    }
+
+    let frequency = parse_rebalance_frequency(&request.rebalance_frequency)?;
+
+    let defaults = WeightingAlgorithm::default();
+    let max_position_size = request.max_position_size.unwrap_or(defaults.max_position_size);
+    let min_position_size = request.min_position_size.unwrap_or(defaults.min_position_size);
+
+    let algorithm = build_backtest_algorithm(&request.algorithm, max_position_size, min_position_size)
+        .map_err(|e| bad_request(e.to_string()))?;
+
+    let risk_free_rate = request.risk_free_rate.unwrap_or(0.0);
+
+    let (universe, dates) = load_backtest_universe(&state, &index_name_upper, from_date, to_date)
+        .await
+        .map_err(|e| {
+            error!("Failed to load backtest universe for {}: {:?}", name, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to load backtest data".to_string(),
+                }),
+            )
+        })?;
+
+    if dates.is_empty() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "NOT_FOUND".to_string(),
+                message: format!(
+                    "No fundamentals data found for index '{}' in the requested window",
+                    name
+                ),
+            }),
+        ));
+    }
+
+    let points = index_engine::run_backtest(&dates, &universe, &algorithm, frequency, 100.0);
+
+    let values: Vec<f64> = points.iter().map(|p| p.index_value).collect();
+    let daily_returns: Vec<Option<f64>> = points.iter().map(|p| p.daily_return).collect();
+
+    let first_value = *values.first().unwrap();
+    let last_value = *values.last().unwrap();
+
+    let data: Vec<PerformanceData> = points
+        .iter()
+        .map(|p| PerformanceData {
+            date: p.date,
+            index_value: p.index_value,
+            daily_return: p.daily_return,
+            cumulative_return: Some(((p.index_value / first_value) - 1.0) * 100.0),
+        })
+        .collect();
+
+    let total_return = ((last_value / first_value) - 1.0) * 100.0;
+    let num_days = (to_date - from_date).num_days() as f64;
+    let years = num_days / 365.0;
+    let annualized_return = if years > 0.0 {
+        ((last_value / first_value).powf(1.0 / years) - 1.0) * 100.0
+    } else {
+        total_return
+    };
+
+    let returns: Vec<f64> = daily_returns.iter().filter_map(|r| *r).collect();
+    let volatility = if returns.len() > 1 {
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let variance =
+            returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+        (variance.sqrt() * 252.0_f64.sqrt()) * 100.0
+    } else {
+        0.0
+    };
+
+    let sharpe_ratio = if volatility > 0.0 {
+        Some((annualized_return - risk_free_rate) / volatility)
+    } else {
+        None
+    };
+
+    let max_dd = max_drawdown(&values);
+    let sortino = sortino_ratio(&returns, annualized_return, risk_free_rate, 0.0);
+
+    let algorithm_label = match request.algorithm {
+        BacktestAlgorithmChoice::MarketCap => "market_cap",
+        BacktestAlgorithmChoice::EqualWeight => "equal_weight",
+        BacktestAlgorithmChoice::ScoreTilted => "score_tilted",
+    };
+
+    Ok(Json(BacktestResponse {
+        index_name: index_name_upper,
+        from_date,
+        to_date,
+        algorithm: algorithm_label.to_string(),
+        rebalance_frequency: request.rebalance_frequency,
+        data,
+        total_return,
+        annualized_return,
+        volatility,
+        sharpe_ratio,
+        max_drawdown: max_dd,
+        sortino_ratio: sortino,
+    }))
+}
+
+/// GET /api/indices/:name/performance
+/// Get historical performance data
+pub async fn get_performance(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(query): Query<PerformanceQuery>,
+) -> Result<Json<PerformanceResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let index_name_upper = name.to_uppercase();
+
+    // Validate index name
+    validate_index_exists(&state, &name, &index_name_upper).await?;
+
     // Parse dates or use defaults
     let from_date = query
         .from
@@ -286,6 +787,19 @@ pub async fn get_performance(
         .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok())
         .unwrap_or_else(|| NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
 
+    let return_type = query.return_type.unwrap_or_else(|| "price".to_string());
+    if return_type != "price" && return_type != "total" {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "BAD_REQUEST".to_string(),
+                message: "return_type must be 'price' or 'total'".to_string(),
+            }),
+        ));
+    }
+
+    let risk_free_rate = query.risk_free_rate.unwrap_or(0.0);
+
     // Fetch performance data from database
     match database::get_index_performance(&state.db, &index_name_upper, from_date, to_date).await {
         Ok(performance_records) => {
@@ -299,26 +813,70 @@ pub async fn get_performance(
                 ));
             }
 
+            // For total-return, reinvest each constituent's dividend on its ex-date by
+            // scaling the index value forward from that point on
+            let values: Vec<f64> = if return_type == "total" {
+                let dividend_yields =
+                    compute_dividend_yields(&state, &index_name_upper, from_date, to_date)
+                        .await
+                        .map_err(|e| {
+                            error!("Failed to compute dividend yields for {}: {:?}", name, e);
+                            (
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                Json(ErrorResponse {
+                                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                                    message: "Failed to compute total-return adjustment".to_string(),
+                                }),
+                            )
+                        })?;
+
+                let mut multiplier = 1.0_f64;
+                performance_records
+                    .iter()
+                    .map(|record| {
+                        if let Some(yield_on_date) = dividend_yields.get(&record.date) {
+                            multiplier *= 1.0 + yield_on_date;
+                        }
+                        record.value as f64 * multiplier
+                    })
+                    .collect()
+            } else {
+                performance_records.iter().map(|r| r.value as f64).collect()
+            };
+
             // Calculate cumulative returns
-            let first_value = performance_records.first().unwrap().value;
-            let last_value = performance_records.last().unwrap().value;
+            let first_value = *values.first().unwrap();
+            let last_value = *values.last().unwrap();
+
+            let daily_returns: Vec<Option<f64>> = if return_type == "total" {
+                let mut result = vec![None];
+                result.extend(values.windows(2).map(|w| Some((w[1] / w[0]) - 1.0)));
+                result
+            } else {
+                performance_records
+                    .iter()
+                    .map(|r| r.daily_return.map(|r| r as f64))
+                    .collect()
+            };
 
             let data: Vec<PerformanceData> = performance_records
                 .iter()
-                .map(|record| {
-                    let cumulative_return = ((record.value / first_value) - 1.0) * 100.0;
+                .zip(values.iter())
+                .zip(daily_returns.iter())
+                .map(|((record, &value), &daily_return)| {
+                    let cumulative_return = ((value / first_value) - 1.0) * 100.0;
                     PerformanceData {
                         date: record.date,
-                        index_value: record.value as f64,
-                        daily_return: record.daily_return.map(|r| r as f64),
-                        cumulative_return: Some(cumulative_return as f64),
+                        index_value: value,
+                        daily_return,
+                        cumulative_return: Some(cumulative_return),
                     }
                 })
                 .collect();
 
             // Calculate statistics
             let total_return = ((last_value / first_value) - 1.0) * 100.0;
-            let num_days = (to_date - from_date).num_days() as f32;
+            let num_days = (to_date - from_date).num_days() as f64;
             let years = num_days / 365.0;
             let annualized_return = if years > 0.0 {
                 ((last_value / first_value).powf(1.0 / years) - 1.0) * 100.0
@@ -327,36 +885,85 @@ pub async fn get_performance(
             };
 
             // Calculate volatility (daily return stddev * sqrt(252))
-            let returns: Vec<f32> = performance_records
-                .iter()
-                .filter_map(|r| r.daily_return)
-                .collect();
+            let returns: Vec<f64> = daily_returns.iter().filter_map(|r| *r).collect();
 
             let volatility = if returns.len() > 1 {
-                let mean = returns.iter().sum::<f32>() / returns.len() as f32;
-                let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f32>()
-                    / (returns.len() - 1) as f32;
-                (variance.sqrt() * 252.0_f32.sqrt()) * 100.0
+                let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+                let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+                    / (returns.len() - 1) as f64;
+                (variance.sqrt() * 252.0_f64.sqrt()) * 100.0
             } else {
                 0.0
             };
 
-            // Calculate Sharpe ratio (assuming 0 risk-free rate)
+            // Calculate Sharpe ratio
             let sharpe_ratio = if volatility > 0.0 {
-                Some((annualized_return / volatility) as f64)
+                Some((annualized_return - risk_free_rate) / volatility)
             } else {
                 None
             };
 
+            let max_dd = max_drawdown(&values);
+            let sortino = sortino_ratio(&returns, annualized_return, risk_free_rate, 0.0);
+
+            let index_returns_by_date: HashMap<NaiveDate, f64> = performance_records
+                .iter()
+                .zip(daily_returns.iter())
+                .filter_map(|(record, &r)| r.map(|r| (record.date, r)))
+                .collect();
+
+            let (beta, alpha, tracking_error) = match database::get_index_performance(
+                &state.db,
+                BENCHMARK_INDEX_NAME,
+                from_date,
+                to_date,
+            )
+            .await
+            {
+                Ok(benchmark_records) if !benchmark_records.is_empty() => {
+                    let benchmark_returns_by_date: HashMap<NaiveDate, f64> = benchmark_records
+                        .iter()
+                        .filter_map(|r| r.daily_return.map(|d| (r.date, d as f64)))
+                        .collect();
+
+                    let benchmark_first = benchmark_records.first().unwrap().value as f64;
+                    let benchmark_last = benchmark_records.last().unwrap().value as f64;
+                    let annualized_benchmark_return = if years > 0.0 {
+                        ((benchmark_last / benchmark_first).powf(1.0 / years) - 1.0) * 100.0
+                    } else {
+                        ((benchmark_last / benchmark_first) - 1.0) * 100.0
+                    };
+
+                    benchmark_risk_metrics(
+                        &index_returns_by_date,
+                        &benchmark_returns_by_date,
+                        annualized_return,
+                        annualized_benchmark_return,
+                        risk_free_rate,
+                    )
+                }
+                Ok(_) => (None, None, None),
+                Err(e) => {
+                    error!("Failed to fetch benchmark series for beta/alpha: {:?}", e);
+                    (None, None, None)
+                }
+            };
+
             Ok(Json(PerformanceResponse {
                 index_name: index_name_upper,
                 from_date,
                 to_date,
+                return_type,
                 data,
-                total_return: total_return as f64,
-                annualized_return: annualized_return as f64,
-                volatility: volatility as f64,
+                total_return,
+                annualized_return,
+                volatility,
                 sharpe_ratio,
+                max_drawdown: max_dd,
+                sortino_ratio: sortino,
+                beta,
+                alpha,
+                tracking_error,
             }))
         }
         Err(e) => {
@@ -371,3 +978,132 @@ pub async fn get_performance(
         }
     }
 }
+
+/// Build the flat, CoinGecko-style ticker rows for an index's current composition: each
+/// constituent's weight, latest price, and 24h return, joined from `CompositionWithCompany`
+/// plus the company's two most recent `Fundamental` rows.
+async fn build_tickers(state: &AppState, index_name: &str) -> anyhow::Result<Vec<TickerInfo>> {
+    let compositions = cached_index_composition(state, index_name).await?;
+    let mut tickers = Vec::with_capacity(compositions.len());
+
+    for c in compositions {
+        let (last_price, change_24h_pct) =
+            match database::get_company_by_ticker(&state.db, &c.ticker).await? {
+                Some(company) => {
+                    let recent =
+                        database::get_fundamentals_by_company(&state.db, company.id, 2).await?;
+                    let last_price = recent.first().and_then(|f| f.price).map(|p| p as f64);
+                    let change_24h_pct = match (recent.first(), recent.get(1)) {
+                        (Some(latest), Some(prior)) => match (latest.price, prior.price) {
+                            (Some(latest_price), Some(prior_price)) if prior_price != 0.0 => {
+                                Some(((latest_price - prior_price) / prior_price * 100.0) as f64)
+                            }
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    (last_price, change_24h_pct)
+                }
+                None => (None, None),
+            };
+
+        tickers.push(TickerInfo {
+            ticker_id: format!("{}-{}", index_name, c.ticker),
+            base_currency: c.ticker,
+            target_currency: index_name.to_string(),
+            last_price,
+            weight: c.weight,
+            change_24h_pct,
+            market_cap: c.market_cap,
+        });
+    }
+
+    Ok(tickers)
+}
+
+/// GET /api/v1/tickers
+/// Flat ticker list across every registered index, in the field-stable shape third-party
+/// dashboards (e.g. CoinGecko-style aggregators) expect - no bespoke parsing required.
+pub async fn list_tickers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TickerInfo>>, (StatusCode, Json<ErrorResponse>)> {
+    let definitions = database::get_all_index_definitions(&state.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to fetch index registry: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to fetch index registry".to_string(),
+                }),
+            )
+        })?;
+
+    let mut tickers = Vec::new();
+    for definition in definitions {
+        match build_tickers(&state, &definition.index_name).await {
+            Ok(index_tickers) => tickers.extend(index_tickers),
+            Err(e) => error!(
+                "Failed to build tickers for {}: {:?}",
+                definition.index_name, e
+            ),
+        }
+    }
+
+    Ok(Json(tickers))
+}
+
+/// GET /api/v1/index/:name/constituents
+/// One index's current constituents in the same field-stable ticker shape as `/api/v1/tickers`
+pub async fn get_constituents(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<ConstituentsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let index_name_upper = name.to_uppercase();
+
+    database::get_index_definition(&state.db, &index_name_upper)
+        .await
+        .map_err(|e| {
+            error!("Database error fetching index definition {}: {:?}", name, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "INTERNAL_SERVER_ERROR".to_string(),
+                    message: "Failed to fetch index data".to_string(),
+                }),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "NOT_FOUND".to_string(),
+                    message: format!("Index '{}' not found", name),
+                }),
+            )
+        })?;
+
+    let as_of_date = match database::get_index_rebalance_dates(&state.db, &index_name_upper).await
+    {
+        Ok(dates) if !dates.is_empty() => dates[0],
+        _ => NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+    };
+
+    let tickers = build_tickers(&state, &index_name_upper).await.map_err(|e| {
+        error!("Database error building tickers for {}: {:?}", name, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: "INTERNAL_SERVER_ERROR".to_string(),
+                message: "Failed to fetch constituent data".to_string(),
+            }),
+        )
+    })?;
+
+    Ok(Json(ConstituentsResponse {
+        index_name: index_name_upper,
+        as_of_date,
+        tickers,
+    }))
+}
@@ -1,15 +1,54 @@
 // Application state
 
+use database::{CompositionWithCompany, IndexMetadata};
+use index_engine::{EqualWeight, InverseScoreRankWeight, MarketCapWeight, WeightingAlgorithm, WeightingStrategy};
+use scheduler::Scheduler;
 use sqlx::PgPool;
 use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cache::TtlCache;
+
+/// Index metadata and composition only change at rebalance time, so a short TTL is enough
+/// to absorb repeated reads within a single burst of traffic without serving stale data
+/// for long after a rebalance.
+const INDEX_CACHE_TTL: Duration = Duration::from_secs(60);
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: Arc<PgPool>,
+    pub metadata_cache: Arc<TtlCache<String, IndexMetadata>>,
+    pub composition_cache: Arc<TtlCache<String, Vec<CompositionWithCompany>>>,
+    pub scheduler: Arc<Scheduler>,
+    pub weighting_strategy: Arc<dyn WeightingStrategy>,
 }
 
 impl AppState {
     pub fn new(db: PgPool) -> Self {
-        Self { db: Arc::new(db) }
+        Self {
+            db: Arc::new(db),
+            metadata_cache: Arc::new(TtlCache::new(INDEX_CACHE_TTL)),
+            composition_cache: Arc::new(TtlCache::new(INDEX_CACHE_TTL)),
+            scheduler: Arc::new(Scheduler::new()),
+            weighting_strategy: weighting_strategy_from_env(),
+        }
+    }
+
+    /// Drop any cached metadata/composition for `index_name`; call after a rebalance or
+    /// ingest writes new compositions or performance rows for it
+    pub fn invalidate_index_cache(&self, index_name: &str) {
+        self.metadata_cache.invalidate(&index_name.to_string());
+        self.composition_cache.invalidate(&index_name.to_string());
+    }
+}
+
+/// Select the index weighting methodology from `WEIGHTING_STRATEGY` (`equal`, `market_cap`, or
+/// `inverse_rank`), defaulting to the standard three-factor linear model if unset or unknown
+fn weighting_strategy_from_env() -> Arc<dyn WeightingStrategy> {
+    match std::env::var("WEIGHTING_STRATEGY").as_deref() {
+        Ok("equal") => Arc::new(EqualWeight),
+        Ok("market_cap") => Arc::new(MarketCapWeight),
+        Ok("inverse_rank") => Arc::new(InverseScoreRankWeight::default()),
+        _ => Arc::new(WeightingAlgorithm::default()),
     }
 }
@@ -0,0 +1,21 @@
+// Vendor-neutral brokerage order-execution abstraction
+
+use anyhow::Result;
+use async_trait::async_trait;
+use index_engine::Order;
+
+/// Result of submitting a single order to a brokerage
+#[derive(Debug, Clone)]
+pub struct OrderReceipt {
+    pub ticker: String,
+    pub brokerage_order_id: String,
+}
+
+/// A brokerage capable of executing a rebalance's order list. `QuestradeClient` is the
+/// default implementation; other brokerages can plug in behind this trait so rebalance
+/// execution isn't hardwired to one vendor.
+#[async_trait]
+pub trait BrokerageClient: Send + Sync {
+    /// Submit a single order and return its brokerage-assigned order id
+    async fn submit_order(&self, order: &Order) -> Result<OrderReceipt>;
+}
@@ -0,0 +1,8 @@
+// Brokerage crate - order execution for index rebalances, vendor-neutral via
+// `BrokerageClient`
+
+pub mod client;
+pub mod questrade;
+
+pub use client::{BrokerageClient, OrderReceipt};
+pub use questrade::QuestradeClient;
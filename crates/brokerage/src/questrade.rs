@@ -0,0 +1,100 @@
+// Questrade order-execution client: a bearer-token-authenticated REST API, the same shape
+// as `data_ingestion::OAuthMarketDataProvider`. Actually placing a live order is gated
+// behind the `live-trading` feature flag so a default build can never fire a real trade -
+// without it, `submit_order` always errors instead of reaching the network.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use index_engine::Order;
+use reqwest::Client;
+use serde::Serialize;
+
+use crate::client::{BrokerageClient, OrderReceipt};
+
+#[cfg(feature = "live-trading")]
+use index_engine::OrderSide;
+
+pub struct QuestradeClient {
+    base_url: String,
+    account_id: String,
+    access_token: String,
+    #[cfg_attr(not(feature = "live-trading"), allow(dead_code))]
+    http: Client,
+}
+
+impl QuestradeClient {
+    pub fn new(base_url: String, account_id: String, access_token: String) -> Self {
+        Self {
+            base_url,
+            account_id,
+            access_token,
+            http: Client::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct QuestradeOrderRequest {
+    symbol: String,
+    quantity: u64,
+    action: &'static str,
+    #[serde(rename = "orderType")]
+    order_type: &'static str,
+    #[serde(rename = "timeInForce")]
+    time_in_force: &'static str,
+}
+
+#[cfg(feature = "live-trading")]
+fn action_for(side: OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "Buy",
+        OrderSide::Sell => "Sell",
+    }
+}
+
+#[async_trait]
+impl BrokerageClient for QuestradeClient {
+    #[cfg(feature = "live-trading")]
+    async fn submit_order(&self, order: &Order) -> Result<OrderReceipt> {
+        let request = QuestradeOrderRequest {
+            symbol: order.ticker.clone(),
+            quantity: order.quantity,
+            action: action_for(order.side),
+            order_type: "Market",
+            time_in_force: "Day",
+        };
+
+        let response: serde_json::Value = self
+            .http
+            .post(format!(
+                "{}/v1/accounts/{}/orders",
+                self.base_url, self.account_id
+            ))
+            .bearer_auth(&self.access_token)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to submit order to Questrade")?
+            .error_for_status()
+            .context("Questrade rejected order")?
+            .json()
+            .await
+            .context("Failed to parse Questrade order response")?;
+
+        let order_id = response["orders"][0]["id"]
+            .as_u64()
+            .context("Questrade order response missing order id")?;
+
+        Ok(OrderReceipt {
+            ticker: order.ticker.clone(),
+            brokerage_order_id: order_id.to_string(),
+        })
+    }
+
+    #[cfg(not(feature = "live-trading"))]
+    async fn submit_order(&self, _order: &Order) -> Result<OrderReceipt> {
+        anyhow::bail!(
+            "Live order submission is disabled - rebuild with `--features live-trading` to submit real orders"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+// Scheduler daemon mode: keep indices fresh without relying on external cron
+
+use anyhow::Result;
+use async_trait::async_trait;
+use scheduler::{Job, Scheduler};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const QUARTER: Duration = Duration::from_secs(91 * 24 * 60 * 60);
+
+struct UpdateFundamentalsJob {
+    concurrency: usize,
+}
+
+#[async_trait]
+impl Job for UpdateFundamentalsJob {
+    fn name(&self) -> String {
+        "update_fundamentals".to_string()
+    }
+
+    fn interval(&self) -> Duration {
+        DAY
+    }
+
+    async fn run(&self) -> Result<()> {
+        super::data::update_fundamentals(self.concurrency).await?;
+        Ok(())
+    }
+}
+
+struct RebalanceJob {
+    index_name: String,
+    quarter: String,
+}
+
+#[async_trait]
+impl Job for RebalanceJob {
+    fn name(&self) -> String {
+        format!("rebalance:{}", self.index_name)
+    }
+
+    fn interval(&self) -> Duration {
+        QUARTER
+    }
+
+    async fn run(&self) -> Result<()> {
+        super::index::rebalance_index(&self.index_name, &self.quarter, false).await
+    }
+}
+
+/// Run the scheduler forever: nightly fundamentals refresh plus quarterly rebalances for
+/// every known index
+pub async fn run(concurrency: usize) -> Result<()> {
+    info!("Starting scheduler daemon");
+
+    let scheduler = Arc::new(Scheduler::new());
+
+    scheduler
+        .schedule(
+            Arc::new(UpdateFundamentalsJob { concurrency }),
+            Duration::ZERO,
+        )
+        .await;
+
+    for index_name in ["SPACEINFRA", "AIINFRA"] {
+        scheduler
+            .schedule(
+                Arc::new(RebalanceJob {
+                    index_name: index_name.to_string(),
+                    quarter: current_quarter(),
+                }),
+                Duration::ZERO,
+            )
+            .await;
+    }
+
+    println!("\n[DAEMON] Scheduler running - nightly fundamentals refresh, quarterly rebalances");
+    println!("   Press Ctrl+C to stop\n");
+
+    scheduler.run().await;
+
+    Ok(())
+}
+
+/// The current calendar quarter as a `QN-YYYY` label, matching the format `rebalance_index`
+/// already expects
+fn current_quarter() -> String {
+    let now = chrono::Utc::now();
+    let quarter = (chrono::Datelike::month(&now) - 1) / 3 + 1;
+    format!("Q{}-{}", quarter, chrono::Datelike::year(&now))
+}
@@ -1,10 +1,21 @@
 // Data management commands
 
-use ai_classifier::{AnthropicClassifier, CompanyInfo};
+use ai_classifier::{AnthropicClassifier, CompanyInfo, OverrideTable};
 use anyhow::{Context, Result};
+use chrono::Utc;
+use data_ingestion::polygon::Financial;
 use data_ingestion::PolygonClient;
+use database::{init_pool, Company, Fundamental};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, Semaphore};
 use tracing::{info, warn};
 
+fn database_url() -> String {
+    std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string())
+}
+
 /// Fetch and ingest ticker data from Polygon.io
 pub async fn ingest_ticker(ticker: &str) -> Result<()> {
     info!("Ingesting data for ticker: {}", ticker);
@@ -103,9 +114,17 @@ pub async fn classify_company(
     Ok(())
 }
 
-/// Classify multiple companies from a CSV file
-pub async fn classify_batch(file_path: &str) -> Result<()> {
-    info!("Batch classification from file: {}", file_path);
+/// Classify multiple companies from a CSV file, applying a curated override table (if given)
+/// as a final deterministic pass over the AI results
+pub async fn classify_batch(
+    file_path: &str,
+    concurrency: usize,
+    overrides_path: Option<&str>,
+) -> Result<()> {
+    info!(
+        "Batch classification from file: {} (concurrency={})",
+        file_path, concurrency
+    );
 
     // Read CSV file
     let contents = std::fs::read_to_string(file_path).context("Failed to read CSV file")?;
@@ -144,7 +163,15 @@ pub async fn classify_batch(file_path: &str) -> Result<()> {
 
     // Classify batch
     let classifier = AnthropicClassifier::new(None)?;
-    let results = classifier.batch_classify(companies, true).await;
+    let (mut results, stats) = classifier.batch_classify(companies, concurrency, true).await;
+
+    if let Some(overrides_path) = overrides_path {
+        let overrides = OverrideTable::load(overrides_path)?;
+        let overridden = overrides.apply(&mut results);
+        if !overridden.is_empty() {
+            println!("\n[+] Applied curated overrides to: {}", overridden.join(", "));
+        }
+    }
 
     // Print summary
     let space_companies: Vec<_> = results.iter().filter(|r| r.is_space_related).collect();
@@ -153,6 +180,10 @@ pub async fn classify_batch(file_path: &str) -> Result<()> {
     println!("   Total: {}", results.len());
     println!("   Space-related: {}", space_companies.len());
     println!("   Non-space: {}", results.len() - space_companies.len());
+    println!(
+        "   Requests sent: {}, Cache hits: {}, Retries: {}, Failures: {}",
+        stats.requests_sent, stats.cache_hits, stats.retries, stats.failures
+    );
 
     // Print space companies
     if !space_companies.is_empty() {
@@ -168,12 +199,201 @@ pub async fn classify_batch(file_path: &str) -> Result<()> {
     Ok(())
 }
 
-/// Update fundamental data for all companies
-pub async fn update_fundamentals(_concurrency: usize) -> Result<()> {
-    info!("Updating fundamental data for all companies");
+/// Years of annual financials spanned when computing `revenue_growth_3y_cagr`
+const CAGR_YEARS: u32 = 3;
+
+/// Outcome of fetching one company's fundamentals, handed from a worker to the DB-writer task
+struct FetchOutcome {
+    ticker: String,
+    result: Result<Option<Fundamental>>,
+    latency: Duration,
+}
+
+/// Counts and timing from a completed `update_fundamentals` run, handed back to the caller
+/// to report however it likes - the CLI prints it, the scheduled server job logs it.
+pub struct FundamentalsRefreshSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub elapsed: Duration,
+    pub median_latency: Duration,
+}
 
-    println!("[!] This feature requires database integration");
-    println!("   Will be implemented after database setup");
+/// Refresh fundamental data for every company in the database.
+///
+/// Tickers are fed from a producer task into a bounded queue; `concurrency` worker tasks each
+/// hold a `Semaphore` permit while calling `PolygonClient::get_financials`/`get_aggregates`, and
+/// feed their results into a single DB-writer task so all `insert_fundamental` calls run one
+/// at a time from that task. The writer goes through the shared pool, so its inserts are not
+/// pinned to one connection. A failure on one ticker is logged and counted - it never aborts
+/// the run.
+pub async fn update_fundamentals(concurrency: usize) -> Result<FundamentalsRefreshSummary> {
+    let concurrency = concurrency.max(1);
+    info!(
+        "Updating fundamental data for all companies (concurrency={})",
+        concurrency
+    );
 
-    Ok(())
+    let pool = init_pool(&database_url())
+        .await
+        .context("Failed to connect to database")?;
+    let companies = database::get_all_companies(&pool).await?;
+
+    // One client for the whole run: every worker clones it, so they all share a single
+    // rate-limiter token bucket and a single warmed ticker-details cache instead of each
+    // tripping Polygon's per-minute quota on its own.
+    let client = PolygonClient::new(None)
+        .context("Failed to create Polygon client - check POLYGON_API_KEY")?;
+
+    println!("\n[+] Refreshing fundamentals for {} companies", companies.len());
+
+    let start = Instant::now();
+
+    let (ticker_tx, ticker_rx) = mpsc::channel::<Company>(concurrency * 2);
+    tokio::spawn(async move {
+        for company in companies {
+            if ticker_tx.send(company).await.is_err() {
+                break;
+            }
+        }
+    });
+    let ticker_rx = Arc::new(Mutex::new(ticker_rx));
+
+    let (result_tx, mut result_rx) = mpsc::channel::<FetchOutcome>(concurrency * 2);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+
+    let mut workers = Vec::with_capacity(concurrency);
+    for _ in 0..concurrency {
+        let ticker_rx = ticker_rx.clone();
+        let semaphore = semaphore.clone();
+        let result_tx = result_tx.clone();
+        let client = client.clone();
+
+        workers.push(tokio::spawn(async move {
+            loop {
+                let company = {
+                    let mut rx = ticker_rx.lock().await;
+                    rx.recv().await
+                };
+                let Some(company) = company else {
+                    break;
+                };
+
+                let _permit = semaphore.acquire().await.expect("semaphore closed");
+
+                let fetch_start = Instant::now();
+                let result = fetch_fundamental(&client, &company).await;
+                let latency = fetch_start.elapsed();
+
+                if result_tx
+                    .send(FetchOutcome {
+                        ticker: company.ticker,
+                        result,
+                        latency,
+                    })
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }));
+    }
+    drop(result_tx);
+
+    let writer = tokio::spawn(async move {
+        let mut succeeded = 0usize;
+        let mut failed = 0usize;
+        let mut skipped = 0usize;
+        let mut latencies = Vec::new();
+
+        while let Some(outcome) = result_rx.recv().await {
+            latencies.push(outcome.latency);
+
+            match outcome.result {
+                Ok(Some(fundamental)) => match database::insert_fundamental(&pool, &fundamental).await {
+                    Ok(_) => succeeded += 1,
+                    Err(e) => {
+                        warn!("Failed to persist fundamentals for {}: {}", outcome.ticker, e);
+                        failed += 1;
+                    }
+                },
+                Ok(None) => skipped += 1,
+                Err(e) => {
+                    warn!("Failed to fetch fundamentals for {}: {}", outcome.ticker, e);
+                    failed += 1;
+                }
+            }
+        }
+
+        (succeeded, failed, skipped, latencies)
+    });
+
+    for worker in workers {
+        worker.await.context("fundamentals worker task panicked")?;
+    }
+
+    let (succeeded, failed, skipped, mut latencies) =
+        writer.await.context("fundamentals writer task panicked")?;
+
+    latencies.sort();
+    let median_latency = latencies.get(latencies.len() / 2).copied().unwrap_or_default();
+
+    Ok(FundamentalsRefreshSummary {
+        succeeded,
+        failed,
+        skipped,
+        elapsed: start.elapsed(),
+        median_latency,
+    })
+}
+
+/// Fetch one company's latest financials and price, returning `Ok(None)` if Polygon has no
+/// financials on file for it. `client` is shared across every worker so they all draw from
+/// the same rate limiter and ticker-details cache.
+async fn fetch_fundamental(
+    client: &PolygonClient,
+    company: &Company,
+) -> Result<Option<Fundamental>> {
+    let financials = client
+        .get_financials(&company.ticker, "annual", CAGR_YEARS + 1)
+        .await?;
+    if financials.is_empty() {
+        return Ok(None);
+    }
+
+    let revenue = latest_revenue(&financials);
+    let revenue_growth_yoy = PolygonClient::calculate_revenue_growth(&financials);
+    let revenue_growth_3y_cagr = PolygonClient::calculate_revenue_cagr(&financials, CAGR_YEARS);
+
+    let aggregates = client
+        .get_aggregates(&company.ticker, 1, "day", None, None, 1)
+        .await?;
+    let latest_bar = aggregates.last();
+
+    Ok(Some(Fundamental {
+        id: 0,
+        company_id: company.id,
+        date: Utc::now().date_naive(),
+        revenue,
+        revenue_growth_yoy,
+        revenue_growth_3y_cagr,
+        market_cap: company.market_cap,
+        price: latest_bar.map(|bar| bar.c as f32),
+        volume: latest_bar.map(|bar| bar.v),
+        created_at: Utc::now(),
+    }))
+}
+
+/// Latest reported revenue from a newest-first `financials` list, in whole dollars
+fn latest_revenue(financials: &[Financial]) -> Option<i64> {
+    financials
+        .first()?
+        .financials
+        .as_ref()?
+        .income_statement
+        .as_ref()?
+        .revenues
+        .as_ref()?
+        .value
 }
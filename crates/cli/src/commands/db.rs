@@ -1,15 +1,19 @@
 // Database management commands
 
 use anyhow::Result;
-use database::{init_pool, run_migrations};
+use database::{get_database_stats, init_pool, migrate, migration_status, rollback};
 use tracing::info;
 
-/// Initialize database and run migrations
+fn database_url() -> String {
+    std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string())
+}
+
+/// Initialize database and apply every pending migration
 pub async fn init_database() -> Result<()> {
     info!("Initializing database");
 
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string());
+    let database_url = database_url();
 
     println!("\n[DB] Initializing Database");
     println!("   URL: {}", database_url);
@@ -19,22 +23,65 @@ pub async fn init_database() -> Result<()> {
     let pool = init_pool(&database_url).await?;
     println!("   [+] Connection established");
 
-    // Run migrations
-    println!("\n[+] Running migrations...");
-    run_migrations(&pool).await?;
-    println!("   [+] Migrations complete");
+    // Apply migrations
+    println!("\n[+] Applying migrations...");
+    let applied = migrate(&pool).await?;
+    if applied.is_empty() {
+        println!("   [+] Schema already up to date");
+    } else {
+        for migration in &applied {
+            println!("   [+] Applied {:04}_{}", migration.version, migration.name);
+        }
+    }
 
     println!("\n[+] Database initialization complete!");
 
     Ok(())
 }
 
+/// Apply every pending migration without the rest of `db init`'s setup output
+pub async fn migrate_database() -> Result<()> {
+    info!("Running pending migrations");
+
+    let pool = init_pool(&database_url()).await?;
+
+    println!("\n[DB] Applying Migrations");
+    let applied = migrate(&pool).await?;
+    if applied.is_empty() {
+        println!("   [+] Nothing to do - schema already up to date");
+    } else {
+        for migration in &applied {
+            println!("   [+] Applied {:04}_{}", migration.version, migration.name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Roll back the `steps` most recently applied migrations
+pub async fn rollback_database(steps: usize) -> Result<()> {
+    info!("Rolling back {} migration(s)", steps);
+
+    let pool = init_pool(&database_url()).await?;
+
+    println!("\n[DB] Rolling Back Migrations");
+    let rolled_back = rollback(&pool, steps).await?;
+    if rolled_back.is_empty() {
+        println!("   [+] Nothing to roll back");
+    } else {
+        for migration in &rolled_back {
+            println!("   [+] Rolled back {:04}_{}", migration.version, migration.name);
+        }
+    }
+
+    Ok(())
+}
+
 /// Check database status
 pub async fn check_status() -> Result<()> {
     info!("Checking database status");
 
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string());
+    let database_url = database_url();
 
     println!("\n[DB] Database Status");
     println!("   URL: {}", database_url);
@@ -42,12 +89,26 @@ pub async fn check_status() -> Result<()> {
     // Try to connect
     println!("\n[+] Testing connection...");
     match init_pool(&database_url).await {
-        Ok(_pool) => {
+        Ok(pool) => {
             println!("   [+] Database is accessible");
 
-            // TODO: Check table counts, last update time, etc.
-            println!("\n[+] Statistics:");
-            println!("   [!] Detailed statistics require SQL queries");
+            let status = migration_status(&pool).await?;
+            println!("\n[+] Schema:");
+            match status.current_version {
+                Some(version) => println!("   Current version: {:04}", version),
+                None => println!("   Current version: (none applied)"),
+            }
+            println!("   Pending migrations: {}", status.pending);
+
+            let stats = get_database_stats(&pool).await?;
+            println!("\n[+] Table row counts:");
+            for table in &stats.table_row_counts {
+                println!("   {}: {}", table.table_name, table.row_count);
+            }
+            match stats.last_company_update {
+                Some(updated_at) => println!("\n   Companies last updated: {}", updated_at),
+                None => println!("\n   Companies last updated: (never)"),
+            }
         }
         Err(e) => {
             println!("   [!] Cannot connect to database");
@@ -58,43 +119,3 @@ pub async fn check_status() -> Result<()> {
 
     Ok(())
 }
-
-/// Reset database (deletes all data)
-pub async fn reset_database() -> Result<()> {
-    info!("Resetting database");
-
-    let database_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string());
-
-    println!("\n[!] RESETTING DATABASE");
-    println!("   This will DELETE ALL DATA!");
-    println!("   URL: {}", database_url);
-
-    let pool = init_pool(&database_url).await?;
-
-    // Drop all tables
-    println!("\n[+] Dropping all tables...");
-    sqlx::query("DROP TABLE IF EXISTS index_performance CASCADE")
-        .execute(&pool)
-        .await?;
-    sqlx::query("DROP TABLE IF EXISTS index_compositions CASCADE")
-        .execute(&pool)
-        .await?;
-    sqlx::query("DROP TABLE IF EXISTS fundamentals CASCADE")
-        .execute(&pool)
-        .await?;
-    sqlx::query("DROP TABLE IF EXISTS companies CASCADE")
-        .execute(&pool)
-        .await?;
-
-    println!("   [+] Tables dropped");
-
-    // Re-run migrations
-    println!("\n[+] Re-running migrations...");
-    run_migrations(&pool).await?;
-    println!("   [+] Migrations complete");
-
-    println!("\n[+] Database reset complete!");
-
-    Ok(())
-}
@@ -1,8 +1,89 @@
 // Index operation commands
 
 use anyhow::{Context, Result};
-use index_engine::{CompanyMetrics, WeightingAlgorithm};
-use tracing::info;
+use brokerage::{BrokerageClient, QuestradeClient};
+use chrono::{NaiveDate, Utc};
+use data_ingestion::{AlphaVantageClient, FundamentalsProvider, PolygonClient, PriceHistoryProvider};
+use database::init_pool;
+use index_engine::{
+    diff_universe, generate_rebalance_orders, run_backtest_from_composition, summarize_backtest,
+    CompanyMetrics, CompositionHistory, IndexConstituent, Position, PriceHistoryByTicker,
+    WeightingAlgorithm,
+};
+use newsletter::RebalancingChanges;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::{info, warn};
+
+/// Benchmark ticker `vs_sp500` is measured against
+const BENCHMARK_TICKER: &str = "SPY";
+
+fn database_url() -> String {
+    std::env::var("DATABASE_URL")
+        .unwrap_or_else(|_| "postgresql://localhost/future_economy_indices".to_string())
+}
+
+/// Index universe companies for which a space-infrastructure classification already
+/// exists. Market cap and revenue growth are pulled live; `space_revenue_pct` and
+/// `segments` still come from the last AI classification until the universe is backed
+/// by the database (see `classify_company`).
+const UNIVERSE: &[(&str, &str, f32, &str)] = &[
+    ("RKLB", "Rocket Lab USA", 80.0, "Launch, Satellites"),
+    ("ASTS", "AST SpaceMobile", 90.0, "Satellites"),
+];
+
+/// Fetch live market cap (Polygon) and revenue growth (Alpha Vantage) for `ticker`,
+/// falling back to `None`/`0.0` if either vendor call fails so one bad ticker doesn't
+/// abort the whole index calculation
+async fn fetch_company_metrics(
+    polygon: &PolygonClient,
+    alpha_vantage: &AlphaVantageClient,
+    ticker: &str,
+    name: &str,
+    space_revenue_pct: f32,
+    segments: &str,
+) -> CompanyMetrics {
+    let market_cap = polygon.get_market_cap(ticker).await.unwrap_or_else(|e| {
+        warn!("Error fetching market cap for {}: {}", ticker, e);
+        None
+    });
+
+    let revenue_growth_rate = alpha_vantage
+        .revenue_growth_rate(ticker)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Error fetching revenue growth for {}: {}", ticker, e);
+            None
+        });
+
+    CompanyMetrics {
+        ticker: ticker.to_string(),
+        name: name.to_string(),
+        market_cap: market_cap.unwrap_or(0) as f64,
+        space_revenue_pct,
+        revenue_growth_rate: revenue_growth_rate.unwrap_or(0.0),
+        segments: Some(segments.to_string()),
+    }
+}
+
+/// Fetch live `CompanyMetrics` for the whole `UNIVERSE` and run them through the default
+/// `WeightingAlgorithm`, producing the freshly calculated target composition. Shared by
+/// `calculate_index` (display only) and `rebalance_index` (diffed against current holdings).
+async fn calculate_target_weights() -> Result<Vec<IndexConstituent>> {
+    let polygon = PolygonClient::new(None).context("Failed to create Polygon client - check POLYGON_API_KEY")?;
+    let alpha_vantage = AlphaVantageClient::new(None)
+        .context("Failed to create Alpha Vantage client - check ALPHA_VANTAGE_API_KEY")?;
+
+    let mut companies = Vec::with_capacity(UNIVERSE.len());
+    for &(ticker, name, space_revenue_pct, segments) in UNIVERSE {
+        companies.push(
+            fetch_company_metrics(&polygon, &alpha_vantage, ticker, name, space_revenue_pct, segments)
+                .await,
+        );
+    }
+
+    let algo = WeightingAlgorithm::default();
+    Ok(algo.calculate_weights(companies))
+}
 
 /// Calculate index composition and weights
 pub async fn calculate_index(name: &str, save: bool) -> Result<()> {
@@ -10,31 +91,9 @@ pub async fn calculate_index(name: &str, save: bool) -> Result<()> {
 
     println!("\n[INDEX] Calculating {} Index", name.to_uppercase());
 
-    // For now, use example data - will connect to database later
-    let companies = vec![
-        CompanyMetrics {
-            ticker: "RKLB".to_string(),
-            name: "Rocket Lab USA".to_string(),
-            market_cap: 25.0e9,
-            space_revenue_pct: 80.0,
-            revenue_growth_rate: 50.0,
-            segments: Some("Launch, Satellites".to_string()),
-        },
-        CompanyMetrics {
-            ticker: "ASTS".to_string(),
-            name: "AST SpaceMobile".to_string(),
-            market_cap: 19.2e9,
-            space_revenue_pct: 90.0,
-            revenue_growth_rate: 120.0,
-            segments: Some("Satellites".to_string()),
-        },
-    ];
-
-    println!("   Universe: {} companies", companies.len());
-
-    // Calculate weights
-    let algo = WeightingAlgorithm::default();
-    let constituents = algo.calculate_weights(companies);
+    let constituents = calculate_target_weights().await?;
+
+    println!("   Universe: {} companies", constituents.len());
 
     // Display results
     println!("\n[+] Index Composition:\n");
@@ -53,7 +112,7 @@ pub async fn calculate_index(name: &str, save: bool) -> Result<()> {
     }
 
     // Summary stats
-    if let Some(stats) = algo.summary_stats(&constituents) {
+    if let Some(stats) = WeightingAlgorithm::default().summary_stats(&constituents) {
         println!("\n[+] Index Statistics:");
         println!("   Total Weight: {:.1}%", stats.total_weight * 100.0);
         println!("   Weighted Avg Space Revenue: {:.1}%", stats.weighted_avg_space_rev_pct);
@@ -64,7 +123,54 @@ pub async fn calculate_index(name: &str, save: bool) -> Result<()> {
 
     if save {
         println!("\n[+] Saving to database...");
-        println!("   [!] Database integration pending");
+
+        let pool = init_pool(&database_url())
+            .await
+            .context("Failed to connect to database")?;
+        let rebalance_date = Utc::now().date_naive();
+
+        let mut rows = Vec::with_capacity(constituents.len());
+        for c in &constituents {
+            let segments = c
+                .segments
+                .as_deref()
+                .map(|s| s.split(',').map(|seg| seg.trim().to_string()).collect());
+
+            let company_id = database::upsert_company(
+                &pool,
+                &database::Company {
+                    id: 0,
+                    ticker: c.ticker.clone(),
+                    name: c.name.clone(),
+                    description: None,
+                    market_cap: Some(c.market_cap as i64),
+                    space_score: Some(c.space_revenue_pct / 100.0),
+                    ai_score: None,
+                    segments,
+                    last_classified_at: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                },
+            )
+            .await?;
+
+            rows.push(database::IndexComposition {
+                id: 0,
+                index_name: name.to_uppercase(),
+                rebalance_date,
+                company_id,
+                weight: c.weight,
+                rank: Some(c.rank as i32),
+                space_revenue_pct: Some(c.space_revenue_pct),
+                revenue_growth_rate: Some(c.revenue_growth_rate),
+                reason_included: None,
+                created_at: Utc::now(),
+            });
+        }
+
+        let inserted =
+            database::commit_rebalance(&pool, &name.to_uppercase(), rebalance_date, &rows).await?;
+        println!("   [+] Committed {} constituents for {}", inserted, rebalance_date);
     }
 
     println!("\n[+] Index calculation complete");
@@ -72,29 +178,271 @@ pub async fn calculate_index(name: &str, save: bool) -> Result<()> {
     Ok(())
 }
 
-/// Rebalance index for a quarter
-pub async fn rebalance_index(name: &str, quarter: &str) -> Result<()> {
+/// Default portfolio NAV assumed when `PORTFOLIO_NAV` isn't set - arbitrary but stable so
+/// repeated dry runs against the same holdings produce comparable order sizes
+const DEFAULT_PORTFOLIO_NAV: f64 = 1_000_000.0;
+
+fn portfolio_nav_from_env() -> f64 {
+    std::env::var("PORTFOLIO_NAV")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_PORTFOLIO_NAV)
+}
+
+/// Parse a "Q1-2025"-style quarter label into the first day of that quarter, falling back
+/// to today if it doesn't match (the label is only used to stamp `RebalancingChanges.date`)
+fn quarter_start_date(quarter: &str) -> NaiveDate {
+    let parse = || -> Option<NaiveDate> {
+        let (q, year) = quarter.split_once('-')?;
+        let q = q.trim_start_matches(['Q', 'q']).parse::<u32>().ok()?;
+        let year = year.parse::<i32>().ok()?;
+        let month = (q.clamp(1, 4) - 1) * 3 + 1;
+        NaiveDate::from_ymd_opt(year, month, 1)
+    };
+
+    parse().unwrap_or_else(|| Utc::now().date_naive())
+}
+
+/// Fetch the most recent daily close for `ticker`, warning (rather than failing the whole
+/// rebalance) if the vendor call comes back empty
+async fn last_close(polygon: &PolygonClient, ticker: &str) -> Option<f64> {
+    match polygon.get_aggregates(ticker, 1, "day", None, None, 1).await {
+        Ok(bars) => bars.last().map(|bar| bar.c),
+        Err(e) => {
+            warn!("Error fetching last price for {}: {}", ticker, e);
+            None
+        }
+    }
+}
+
+/// Rebalance index for a quarter: diffs the index's last recorded composition (reconstructed
+/// as an implied portfolio of shares at `PORTFOLIO_NAV`) against a freshly calculated target
+/// composition, and emits a concrete buy/sell order list. Defaults to a dry run that only
+/// prints the orders; pass `live` to submit them through `QuestradeClient`, which itself
+/// refuses unless the binary was built with the `live-trading` feature.
+pub async fn rebalance_index(name: &str, quarter: &str, live: bool) -> Result<()> {
     info!("Rebalancing index {} for {}", name, quarter);
 
     println!("\n[INDEX] Rebalancing {} for {}", name.to_uppercase(), quarter);
-    println!("   [!] This feature requires database integration");
-    println!("   Will compare current composition vs new calculation");
-    println!("   and generate trades for rebalancing");
+
+    let index_name = name.to_uppercase();
+    let portfolio_nav = portfolio_nav_from_env();
+
+    let pool = init_pool(&database_url())
+        .await
+        .context("Failed to connect to database")?;
+    let current = database::get_index_composition_with_companies(&pool, &index_name).await?;
+
+    let polygon = PolygonClient::new(None).context("Failed to create Polygon client - check POLYGON_API_KEY")?;
+
+    let mut holdings: BTreeMap<String, Position> = BTreeMap::new();
+    for row in &current {
+        let Some(last_price) = last_close(&polygon, &row.ticker).await else {
+            continue;
+        };
+        let shares = (row.weight * portfolio_nav) / last_price;
+        holdings.insert(
+            row.ticker.clone(),
+            Position {
+                shares,
+                last_price,
+            },
+        );
+    }
+
+    let constituents = calculate_target_weights().await?;
+    let target_weights: BTreeMap<String, f32> = constituents
+        .iter()
+        .map(|c| (c.ticker.clone(), c.weight))
+        .collect();
+
+    let mut target_prices: BTreeMap<String, f64> = BTreeMap::new();
+    for ticker in target_weights.keys() {
+        if let Some(price) = holdings.get(ticker).map(|p| p.last_price) {
+            target_prices.insert(ticker.clone(), price);
+        } else if let Some(price) = last_close(&polygon, ticker).await {
+            target_prices.insert(ticker.clone(), price);
+        }
+    }
+
+    let orders = generate_rebalance_orders(&holdings, &target_weights, &target_prices, portfolio_nav);
+    let universe_changes = diff_universe(&holdings, &target_weights);
+
+    if orders.is_empty() {
+        println!("\n[+] No rebalancing trades required - portfolio already matches target weights");
+    } else {
+        println!("\n[+] Rebalance Orders ({}):\n", orders.len());
+        println!("{:<8} {:<6} {:<10} {:<12}", "Ticker", "Side", "Quantity", "Price");
+        println!("{}", "-".repeat(40));
+        for order in &orders {
+            println!(
+                "{:<8} {:<6} {:<10} {:>10.2}",
+                order.ticker,
+                format!("{:?}", order.side),
+                order.quantity,
+                order.last_price
+            );
+        }
+    }
+
+    let changes = RebalancingChanges {
+        added: universe_changes.added,
+        removed: universe_changes.removed,
+        date: quarter_start_date(quarter),
+    };
+    println!(
+        "\n[+] Universe changes: {} added, {} removed",
+        changes.added.len(),
+        changes.removed.len()
+    );
+
+    if live {
+        println!("\n[+] Submitting orders to Questrade (live mode)...");
+        let brokerage = QuestradeClient::new(
+            std::env::var("QUESTRADE_BASE_URL").unwrap_or_else(|_| "https://api01.iq.questrade.com".to_string()),
+            std::env::var("QUESTRADE_ACCOUNT_ID").unwrap_or_default(),
+            std::env::var("QUESTRADE_ACCESS_TOKEN").unwrap_or_default(),
+        );
+        for order in &orders {
+            match brokerage.submit_order(order).await {
+                Ok(receipt) => println!("   [+] {} -> order {}", receipt.ticker, receipt.brokerage_order_id),
+                Err(e) => warn!("Failed to submit order for {}: {}", order.ticker, e),
+            }
+        }
+    } else {
+        println!("   [!] Dry run - pass --live to submit these orders to the brokerage");
+    }
 
     Ok(())
 }
 
-/// Backtest index performance
+/// Load the composition weights recorded at every past rebalance, keyed by rebalance
+/// date, along with the set of tickers that ever appeared in one of them
+async fn load_composition_history(
+    pool: &sqlx::PgPool,
+    index_name: &str,
+    to_date: NaiveDate,
+) -> Result<(CompositionHistory, BTreeSet<String>)> {
+    let rebalance_dates: Vec<NaiveDate> = database::get_index_rebalance_dates(pool, index_name)
+        .await?
+        .into_iter()
+        .filter(|d| *d <= to_date)
+        .collect();
+
+    let mut history = CompositionHistory::new();
+    let mut tickers = BTreeSet::new();
+
+    for date in rebalance_dates {
+        let rows =
+            database::get_index_composition_with_companies_as_of(pool, index_name, date).await?;
+
+        let mut weights = BTreeMap::new();
+        for row in rows {
+            tickers.insert(row.ticker.clone());
+            weights.insert(row.ticker, row.weight as f32);
+        }
+        history.insert(date, weights);
+    }
+
+    Ok((history, tickers))
+}
+
+/// Fetch daily adjusted close for every ticker in `tickers` over `[from, to]`, skipping
+/// (and warning on) any ticker the provider fails to return history for
+async fn load_price_history(
+    alpha_vantage: &AlphaVantageClient,
+    tickers: &BTreeSet<String>,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> PriceHistoryByTicker {
+    let mut prices = PriceHistoryByTicker::new();
+
+    for ticker in tickers {
+        match alpha_vantage.get_daily_adjusted_close(ticker, from, to).await {
+            Ok(series) => {
+                prices.insert(ticker.clone(), series);
+            }
+            Err(e) => warn!("Error fetching price history for {}: {}", ticker, e),
+        }
+    }
+
+    prices
+}
+
+/// Backtest index performance: replays the index's actual rebalance history against daily
+/// adjusted-close prices, producing the same `total_return`/`ytd_return`/`vs_sp500` figures
+/// the newsletter generator expects (see `newsletter::NewsletterData`)
 pub async fn backtest_index(name: &str, from: &str, to: Option<&str>) -> Result<()> {
     info!("Backtesting index {} from {} to {:?}", name, from, to);
 
     println!("\n[INDEX] Backtesting {} Index", name.to_uppercase());
     println!("   From: {}", from);
     println!("   To: {}", to.unwrap_or("today"));
-    println!("\n   [!] This feature requires:");
-    println!("      1. Historical price data");
-    println!("      2. Index composition history");
-    println!("      3. Backtest engine implementation");
+
+    let from_date =
+        NaiveDate::parse_from_str(from, "%Y-%m-%d").context("from must be in YYYY-MM-DD format")?;
+    let to_date = match to {
+        Some(t) => NaiveDate::parse_from_str(t, "%Y-%m-%d").context("to must be in YYYY-MM-DD format")?,
+        None => Utc::now().date_naive(),
+    };
+
+    let index_name = name.to_uppercase();
+    let pool = init_pool(&database_url())
+        .await
+        .context("Failed to connect to database")?;
+
+    let (composition_history, tickers) =
+        load_composition_history(&pool, &index_name, to_date).await?;
+
+    if composition_history.is_empty() {
+        println!("\n   [!] No composition history found for {} - run `index rebalance` first", index_name);
+        return Ok(());
+    }
+
+    let alpha_vantage = AlphaVantageClient::new(None)
+        .context("Failed to create Alpha Vantage client - check ALPHA_VANTAGE_API_KEY")?;
+
+    let prices = load_price_history(&alpha_vantage, &tickers, from_date, to_date).await;
+
+    let dates: Vec<NaiveDate> = prices
+        .values()
+        .flat_map(|series| series.keys().copied())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    if dates.is_empty() {
+        println!("\n   [!] No price history found for the {} universe in this window", index_name);
+        return Ok(());
+    }
+
+    let points = run_backtest_from_composition(&dates, &composition_history, &prices, 100.0);
+
+    let benchmark_prices = alpha_vantage
+        .get_daily_adjusted_close(BENCHMARK_TICKER, from_date, to_date)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Error fetching benchmark ({}) price history: {}", BENCHMARK_TICKER, e);
+            BTreeMap::new()
+        });
+    let benchmark_composition: CompositionHistory =
+        [(dates[0], [(BENCHMARK_TICKER.to_string(), 1.0)].into())].into();
+    let benchmark_prices: PriceHistoryByTicker =
+        [(BENCHMARK_TICKER.to_string(), benchmark_prices)].into();
+    let benchmark_points =
+        run_backtest_from_composition(&dates, &benchmark_composition, &benchmark_prices, 100.0);
+
+    let summary = summarize_backtest(&points, &benchmark_points);
+
+    println!("\n[+] Backtest Results:");
+    println!("   Data Points: {}", points.len());
+    println!("   Total Return: {:.1}%", summary.total_return);
+    println!("   YTD Return: {:.1}%", summary.ytd_return);
+    println!("   Annualized Volatility: {:.1}%", summary.annualized_volatility);
+    println!("   Max Drawdown: {:.1}%", summary.max_drawdown);
+    println!("   vs {}: {:+.1}%", BENCHMARK_TICKER, summary.vs_sp500);
+
+    println!("\n[+] Backtest complete");
 
     Ok(())
 }
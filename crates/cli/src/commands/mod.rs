@@ -0,0 +1,6 @@
+// CLI subcommand implementations
+
+pub mod daemon;
+pub mod data;
+pub mod db;
+pub mod index;
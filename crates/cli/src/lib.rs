@@ -0,0 +1,5 @@
+// Library surface for the CLI crate's command implementations, so other binaries (the
+// api-server's scheduled jobs) can call the same refresh/rebalance logic the CLI does
+// instead of re-implementing it.
+
+pub mod commands;
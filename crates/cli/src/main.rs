@@ -7,7 +7,7 @@ use clap::{Parser, Subcommand};
 use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 
-mod commands;
+use cli::commands;
 
 #[derive(Parser)]
 #[command(name = "future-indices-cli")]
@@ -34,6 +34,13 @@ enum Commands {
     /// Database management commands
     #[command(subcommand)]
     Db(DbCommands),
+
+    /// Run the scheduler daemon (nightly fundamentals refresh, quarterly rebalances)
+    Daemon {
+        /// Number of concurrent requests for the nightly fundamentals refresh
+        #[arg(short, long, default_value = "5")]
+        concurrency: usize,
+    },
 }
 
 #[derive(Subcommand)]
@@ -65,6 +72,14 @@ enum DataCommands {
         /// Path to CSV file with tickers
         #[arg(short, long)]
         file: String,
+
+        /// Number of concurrent classification requests
+        #[arg(short, long, default_value = "5")]
+        concurrency: usize,
+
+        /// Path to a JSON table of curated ticker overrides, applied after classification
+        #[arg(short, long)]
+        overrides: Option<String>,
     },
 
     /// Update fundamental data for all companies in database
@@ -97,6 +112,11 @@ enum IndexCommands {
         /// Quarter (e.g., Q1-2025)
         #[arg(short, long)]
         quarter: String,
+
+        /// Submit the generated orders to the brokerage instead of just printing them
+        /// (requires the binary to be built with `--features live-trading`)
+        #[arg(short, long)]
+        live: bool,
     },
 
     /// Backtest index performance
@@ -120,18 +140,21 @@ enum IndexCommands {
 
 #[derive(Subcommand)]
 enum DbCommands {
-    /// Initialize database and run migrations
+    /// Initialize database and apply every pending migration
     Init,
 
-    /// Check database status
-    Status,
+    /// Apply all pending migrations
+    Migrate,
 
-    /// Reset database (WARNING: deletes all data)
-    Reset {
-        /// Confirm reset
-        #[arg(short, long)]
-        confirm: bool,
+    /// Roll back the most recently applied migration(s)
+    Rollback {
+        /// Number of migrations to roll back
+        #[arg(short, long, default_value = "1")]
+        steps: usize,
     },
+
+    /// Check database status (schema version, pending migrations, table row counts)
+    Status,
 }
 
 #[tokio::main]
@@ -173,11 +196,17 @@ async fn main() -> Result<()> {
             } => {
                 commands::data::classify_company(&ticker, name, description).await?;
             }
-            DataCommands::ClassifyBatch { file } => {
-                commands::data::classify_batch(&file).await?;
+            DataCommands::ClassifyBatch { file, concurrency, overrides } => {
+                commands::data::classify_batch(&file, concurrency, overrides.as_deref()).await?;
             }
             DataCommands::UpdateFundamentals { concurrency } => {
-                commands::data::update_fundamentals(concurrency).await?;
+                let summary = commands::data::update_fundamentals(concurrency).await?;
+                println!("\n[+] Fundamentals refresh complete!");
+                println!("   Succeeded: {}", summary.succeeded);
+                println!("   Failed: {}", summary.failed);
+                println!("   Skipped (no data): {}", summary.skipped);
+                println!("   Elapsed: {:.1}s", summary.elapsed.as_secs_f64());
+                println!("   Median fetch latency: {}ms", summary.median_latency.as_millis());
             }
         },
 
@@ -185,8 +214,8 @@ async fn main() -> Result<()> {
             IndexCommands::Calculate { name, save } => {
                 commands::index::calculate_index(&name, save).await?;
             }
-            IndexCommands::Rebalance { name, quarter } => {
-                commands::index::rebalance_index(&name, &quarter).await?;
+            IndexCommands::Rebalance { name, quarter, live } => {
+                commands::index::rebalance_index(&name, &quarter, live).await?;
             }
             IndexCommands::Backtest { name, from, to } => {
                 commands::index::backtest_index(&name, &from, to.as_deref()).await?;
@@ -200,18 +229,20 @@ async fn main() -> Result<()> {
             DbCommands::Init => {
                 commands::db::init_database().await?;
             }
+            DbCommands::Migrate => {
+                commands::db::migrate_database().await?;
+            }
+            DbCommands::Rollback { steps } => {
+                commands::db::rollback_database(steps).await?;
+            }
             DbCommands::Status => {
                 commands::db::check_status().await?;
             }
-            DbCommands::Reset { confirm } => {
-                if confirm {
-                    commands::db::reset_database().await?;
-                } else {
-                    println!("⚠️  Database reset requires --confirm flag");
-                    println!("   This will delete ALL data!");
-                }
-            }
         },
+
+        Commands::Daemon { concurrency } => {
+            commands::daemon::run(concurrency).await?;
+        }
     }
 
     Ok(())
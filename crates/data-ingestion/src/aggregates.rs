@@ -0,0 +1,220 @@
+// Local OHLCV resampling and gap-aware backfill, so a single finest-granularity pull can
+// serve coarser views (weekly/monthly) without extra vendor API calls.
+
+use crate::polygon::AggregateBar;
+use crate::provider::MarketDataProvider;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use database::Fundamental;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A coarser period a finer-grained bar series can be resampled up into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResamplePeriod {
+    Weekly,
+    Monthly,
+}
+
+impl ResamplePeriod {
+    /// Epoch ms of the start of the bucket `timestamp_ms` falls into
+    fn bucket_start_ms(&self, timestamp_ms: i64) -> i64 {
+        match self {
+            ResamplePeriod::Weekly => {
+                // 1970-01-01 was a Thursday, so flooring epoch ms to whole weeks gives
+                // stable Thursday-anchored bucket boundaries without needing a calendar
+                const WEEK_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+                timestamp_ms.div_euclid(WEEK_MS) * WEEK_MS
+            }
+            ResamplePeriod::Monthly => {
+                let date = DateTime::from_timestamp_millis(timestamp_ms)
+                    .map(|dt| dt.date_naive())
+                    .unwrap_or_else(|| NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+                NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc()
+                    .timestamp_millis()
+            }
+        }
+    }
+}
+
+/// Bucket `bars` (any order) into `period`-sized OHLCV candles: open/close come from the
+/// bucket's first/last bar by timestamp, high/low are the bucket's max/min, volume and
+/// transaction count sum, and `vw` is the volume-weighted mean of each bar's own `vw`
+/// (falling back to that bar's close when it has none).
+pub fn resample(bars: &[AggregateBar], period: ResamplePeriod) -> Vec<AggregateBar> {
+    let mut buckets: BTreeMap<i64, Vec<&AggregateBar>> = BTreeMap::new();
+
+    for bar in bars {
+        buckets
+            .entry(period.bucket_start_ms(bar.t))
+            .or_default()
+            .push(bar);
+    }
+
+    buckets
+        .into_iter()
+        .map(|(bucket_start, mut bucket_bars)| {
+            bucket_bars.sort_by_key(|b| b.t);
+
+            let total_volume: i64 = bucket_bars.iter().map(|b| b.v).sum();
+            let weighted_sum: f64 = bucket_bars
+                .iter()
+                .map(|b| b.vw.unwrap_or(b.c) * b.v as f64)
+                .sum();
+            let vw = if total_volume > 0 {
+                Some(weighted_sum / total_volume as f64)
+            } else {
+                None
+            };
+
+            let transaction_counts: Vec<i64> = bucket_bars.iter().filter_map(|b| b.n).collect();
+            let n = if transaction_counts.is_empty() {
+                None
+            } else {
+                Some(transaction_counts.iter().sum())
+            };
+
+            AggregateBar {
+                t: bucket_start,
+                o: bucket_bars.first().unwrap().o,
+                h: bucket_bars.iter().map(|b| b.h).fold(f64::MIN, f64::max),
+                l: bucket_bars.iter().map(|b| b.l).fold(f64::MAX, f64::min),
+                c: bucket_bars.last().unwrap().c,
+                v: total_volume,
+                vw,
+                n,
+            }
+        })
+        .collect()
+}
+
+/// The gap-filled daily series plus its weekly/monthly resamplings
+#[derive(Debug, Clone)]
+pub struct BackfillResult {
+    pub daily: Vec<AggregateBar>,
+    pub weekly: Vec<AggregateBar>,
+    pub monthly: Vec<AggregateBar>,
+}
+
+/// Backfill a company's daily price history over `[from_date, to_date]`, then derive
+/// weekly/monthly series from it, modeled as two passes:
+///
+/// - Trades pass: find the dates in range missing a `fundamentals` row, fetch just those
+///   gaps from `provider` at daily granularity, and insert them.
+/// - Candles pass: resample the now-complete daily series up into weekly and monthly bars.
+pub async fn backfill_and_resample(
+    pool: &PgPool,
+    provider: &dyn MarketDataProvider,
+    company_id: i32,
+    ticker: &str,
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> Result<BackfillResult> {
+    let existing = database::get_fundamentals_by_company(pool, company_id, 10_000).await?;
+    let existing_dates: BTreeSet<NaiveDate> = existing
+        .iter()
+        .filter(|f| f.date >= from_date && f.date <= to_date)
+        .map(|f| f.date)
+        .collect();
+
+    for (gap_from, gap_to) in missing_date_ranges(&existing_dates, from_date, to_date) {
+        let bars = provider
+            .get_aggregates(ticker, 1, "day", Some(gap_from), Some(gap_to), 5000)
+            .await?;
+
+        for bar in &bars {
+            let date = DateTime::from_timestamp_millis(bar.t)
+                .map(|dt| dt.date_naive())
+                .unwrap_or(gap_from);
+
+            // Upsert rather than insert: gaps can be re-backfilled over overlapping
+            // ranges, and the stored row may already carry revenue data this fetch doesn't
+            database::upsert_fundamental(
+                pool,
+                &Fundamental {
+                    id: 0,
+                    company_id,
+                    date,
+                    revenue: None,
+                    revenue_growth_yoy: None,
+                    revenue_growth_3y_cagr: None,
+                    market_cap: None,
+                    price: Some(bar.c as f32),
+                    volume: Some(bar.v),
+                    created_at: Utc::now(),
+                },
+            )
+            .await?;
+        }
+    }
+
+    let daily: Vec<AggregateBar> = database::get_fundamentals_by_company(pool, company_id, 10_000)
+        .await?
+        .into_iter()
+        .filter(|f| f.date >= from_date && f.date <= to_date)
+        .filter_map(|f| fundamental_to_bar(&f))
+        .collect();
+
+    let weekly = resample(&daily, ResamplePeriod::Weekly);
+    let monthly = resample(&daily, ResamplePeriod::Monthly);
+
+    Ok(BackfillResult {
+        daily,
+        weekly,
+        monthly,
+    })
+}
+
+/// Collapse the dates in `[from_date, to_date]` absent from `existing` into contiguous
+/// `(start, end)` ranges, so each gap needs only one vendor request instead of one per day
+fn missing_date_ranges(
+    existing: &BTreeSet<NaiveDate>,
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut ranges = Vec::new();
+    let mut gap_start: Option<NaiveDate> = None;
+    let mut date = from_date;
+
+    while date <= to_date {
+        if existing.contains(&date) {
+            if let Some(start) = gap_start.take() {
+                ranges.push((start, date - Duration::days(1)));
+            }
+        } else if gap_start.is_none() {
+            gap_start = Some(date);
+        }
+        date += Duration::days(1);
+    }
+
+    if let Some(start) = gap_start {
+        ranges.push((start, to_date));
+    }
+
+    ranges
+}
+
+/// A daily `Fundamental` row reinterpreted as a single-day OHLCV bar (open = high = low =
+/// close = the stored price, since `fundamentals` keeps only a closing price per date)
+fn fundamental_to_bar(fundamental: &Fundamental) -> Option<AggregateBar> {
+    let price = fundamental.price? as f64;
+
+    Some(AggregateBar {
+        t: fundamental
+            .date
+            .and_hms_opt(0, 0, 0)?
+            .and_utc()
+            .timestamp_millis(),
+        o: price,
+        h: price,
+        l: price,
+        c: price,
+        v: fundamental.volume.unwrap_or(0),
+        vw: Some(price),
+        n: None,
+    })
+}
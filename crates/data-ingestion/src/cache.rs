@@ -0,0 +1,49 @@
+// Concurrent, TTL'd ticker details cache, so repeat lookups of the same ticker during a
+// batch run (classification, fundamentals refresh) don't re-hit the network
+
+use crate::polygon::TickerDetails;
+use dashmap::DashMap;
+use std::time::{Duration, Instant};
+
+struct CacheEntry {
+    details: TickerDetails,
+    inserted_at: Instant,
+}
+
+/// `TickerDetails` keyed by ticker symbol, shared across clones of a client via `Arc` so
+/// concurrent workers warm and read one cache instead of each keeping its own
+pub struct TickerCache {
+    entries: DashMap<String, CacheEntry>,
+    ttl: Duration,
+}
+
+impl TickerCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: DashMap::new(),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, ticker: &str) -> Option<TickerDetails> {
+        let entry = self.entries.get(ticker)?;
+        if entry.inserted_at.elapsed() > self.ttl {
+            return None;
+        }
+        Some(entry.details.clone())
+    }
+
+    pub fn insert(&self, ticker: String, details: TickerDetails) {
+        self.entries.insert(
+            ticker,
+            CacheEntry {
+                details,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    pub fn invalidate(&self, ticker: &str) {
+        self.entries.remove(ticker);
+    }
+}
@@ -0,0 +1,261 @@
+// Alpha Vantage fundamentals client (EARNINGS + INCOME_STATEMENT), behind a vendor-neutral
+// `FundamentalsProvider` trait so revenue-growth inputs to the index aren't hardwired to
+// one fundamentals vendor.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+const BASE_URL: &str = "https://www.alphavantage.co/query";
+// Below this many quarterly reports, a trailing-four-quarter YoY comparison would reach
+// past the available history, so fall back to annual CAGR instead.
+const MIN_QUARTERS_FOR_TTM_GROWTH: usize = 8;
+
+/// A single annual or quarterly report from Alpha Vantage's `EARNINGS` endpoint.
+/// `reported_eps` arrives as a string, and a missing value is the literal `"None"`
+/// rather than a JSON null.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EarningsReport {
+    #[serde(rename = "fiscalDateEnding")]
+    pub fiscal_date_ending: NaiveDate,
+    #[serde(rename = "reportedEPS", deserialize_with = "deserialize_optional_av_f64")]
+    pub reported_eps: Option<f64>,
+}
+
+/// Response shape of Alpha Vantage's `EARNINGS` endpoint: annual and quarterly reports,
+/// newest first.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EarningsData {
+    pub symbol: String,
+    #[serde(rename = "annualEarnings")]
+    pub annual_earnings: Vec<EarningsReport>,
+    #[serde(rename = "quarterlyEarnings")]
+    pub quarterly_earnings: Vec<EarningsReport>,
+}
+
+/// A single annual or quarterly report from Alpha Vantage's `INCOME_STATEMENT` endpoint.
+/// `total_revenue` has the same string-or-`"None"` quirk as `reported_eps` above.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IncomeStatementReport {
+    #[serde(rename = "fiscalDateEnding")]
+    pub fiscal_date_ending: NaiveDate,
+    #[serde(rename = "totalRevenue", deserialize_with = "deserialize_optional_av_i64")]
+    pub total_revenue: Option<i64>,
+}
+
+/// Response shape of Alpha Vantage's `INCOME_STATEMENT` endpoint: annual and quarterly
+/// reports, newest first.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IncomeStatementData {
+    pub symbol: String,
+    #[serde(rename = "annualReports")]
+    pub annual_reports: Vec<IncomeStatementReport>,
+    #[serde(rename = "quarterlyReports")]
+    pub quarterly_reports: Vec<IncomeStatementReport>,
+}
+
+/// Alpha Vantage represents every numeric field as a string, using the literal `"None"`
+/// for a missing value instead of a JSON null. Used by `reported_eps`; `total_revenue` goes
+/// through the `i64` twin below.
+fn deserialize_optional_av_f64<'de, D>(deserializer: D) -> std::result::Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = Option::<String>::deserialize(deserializer)?;
+    Ok(raw.filter(|s| s != "None").and_then(|s| s.parse().ok()))
+}
+
+fn deserialize_optional_av_i64<'de, D>(deserializer: D) -> std::result::Result<Option<i64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw = Option::<String>::deserialize(deserializer)?;
+    Ok(raw.filter(|s| s != "None").and_then(|s| s.parse().ok()))
+}
+
+/// A source of fundamental (earnings, revenue) data for computing index inputs like
+/// `revenue_growth_rate`. `AlphaVantageClient` is the default implementation; other
+/// vendors can plug in behind this trait the same way `MarketDataProvider` does for
+/// price/reference data.
+#[async_trait]
+pub trait FundamentalsProvider: Send + Sync {
+    /// Fetch annual and quarterly EPS history
+    async fn get_earnings(&self, ticker: &str) -> Result<EarningsData>;
+
+    /// Fetch annual and quarterly revenue history
+    async fn get_income_statement(&self, ticker: &str) -> Result<IncomeStatementData>;
+
+    /// Year-over-year trailing-four-quarter revenue growth, falling back to annual
+    /// revenue CAGR when fewer than `MIN_QUARTERS_FOR_TTM_GROWTH` quarters exist
+    async fn revenue_growth_rate(&self, ticker: &str) -> Result<Option<f32>> {
+        let income = self.get_income_statement(ticker).await?;
+        Ok(revenue_growth_rate(
+            &income.quarterly_reports,
+            &income.annual_reports,
+        ))
+    }
+}
+
+/// Trailing-four-quarter revenue vs. the prior four quarters (YoY), falling back to
+/// annual revenue CAGR when fewer than `MIN_QUARTERS_FOR_TTM_GROWTH` quarters exist.
+/// Reports are expected newest-first, matching Alpha Vantage's ordering.
+fn revenue_growth_rate(
+    quarterly: &[IncomeStatementReport],
+    annual: &[IncomeStatementReport],
+) -> Option<f32> {
+    if quarterly.len() >= MIN_QUARTERS_FOR_TTM_GROWTH {
+        let trailing: i64 = quarterly[0..4].iter().filter_map(|r| r.total_revenue).sum();
+        let prior: i64 = quarterly[4..8].iter().filter_map(|r| r.total_revenue).sum();
+
+        if prior == 0 {
+            return None;
+        }
+
+        return Some(((trailing - prior) as f32 / prior as f32) * 100.0);
+    }
+
+    annual_revenue_cagr(annual)
+}
+
+/// CAGR between the oldest and newest annual revenue figures, annualized over the number
+/// of year-over-year periods between them
+fn annual_revenue_cagr(annual: &[IncomeStatementReport]) -> Option<f32> {
+    if annual.len() < 2 {
+        return None;
+    }
+
+    let latest = annual.first()?.total_revenue? as f64;
+    let oldest = annual.last()?.total_revenue? as f64;
+
+    if oldest <= 0.0 {
+        return None;
+    }
+
+    let years = (annual.len() - 1) as f64;
+    Some((((latest / oldest).powf(1.0 / years) - 1.0) * 100.0) as f32)
+}
+
+#[derive(Clone)]
+pub struct AlphaVantageClient {
+    api_key: String,
+    client: Client,
+}
+
+impl AlphaVantageClient {
+    /// Create a new Alpha Vantage client with API key from environment or parameter
+    pub fn new(api_key: Option<String>) -> Result<Self> {
+        let api_key = api_key
+            .or_else(|| std::env::var("ALPHA_VANTAGE_API_KEY").ok())
+            .context("ALPHA_VANTAGE_API_KEY must be set in environment or passed to constructor")?;
+
+        Ok(Self {
+            api_key,
+            client: Client::new(),
+        })
+    }
+
+    async fn fetch(&self, function: &str, ticker: &str) -> Result<serde_json::Value> {
+        self.fetch_with_params(function, ticker, &[]).await
+    }
+
+    /// Like `fetch`, but with extra query params beyond `function`/`symbol`/`apikey`
+    /// (e.g. `outputsize=full` for the daily price history endpoints)
+    pub(crate) async fn fetch_with_params(
+        &self,
+        function: &str,
+        ticker: &str,
+        extra_params: &[(&str, &str)],
+    ) -> Result<serde_json::Value> {
+        let mut query = vec![
+            ("function", function),
+            ("symbol", ticker),
+            ("apikey", self.api_key.as_str()),
+        ];
+        query.extend_from_slice(extra_params);
+
+        let response = self
+            .client
+            .get(BASE_URL)
+            .query(&query)
+            .send()
+            .await
+            .context("Failed to send request to Alpha Vantage")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Alpha Vantage API error: {}", response.status());
+        }
+
+        response
+            .json::<serde_json::Value>()
+            .await
+            .context("Failed to parse Alpha Vantage response")
+    }
+}
+
+#[async_trait]
+impl FundamentalsProvider for AlphaVantageClient {
+    async fn get_earnings(&self, ticker: &str) -> Result<EarningsData> {
+        let json = self.fetch("EARNINGS", ticker).await?;
+        serde_json::from_value(json).context("Failed to parse EARNINGS response")
+    }
+
+    async fn get_income_statement(&self, ticker: &str) -> Result<IncomeStatementData> {
+        let json = self.fetch("INCOME_STATEMENT", ticker).await?;
+        serde_json::from_value(json).context("Failed to parse INCOME_STATEMENT response")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(date: &str, revenue: Option<i64>) -> IncomeStatementReport {
+        IncomeStatementReport {
+            fiscal_date_ending: date.parse().unwrap(),
+            total_revenue: revenue,
+        }
+    }
+
+    #[test]
+    fn test_ttm_growth_from_eight_quarters() {
+        // Newest first: 4 trailing quarters of 110 each vs. 4 prior quarters of 100 each
+        let quarterly = vec![
+            report("2024-12-31", Some(110)),
+            report("2024-09-30", Some(110)),
+            report("2024-06-30", Some(110)),
+            report("2024-03-31", Some(110)),
+            report("2023-12-31", Some(100)),
+            report("2023-09-30", Some(100)),
+            report("2023-06-30", Some(100)),
+            report("2023-03-31", Some(100)),
+        ];
+
+        let growth = revenue_growth_rate(&quarterly, &[]);
+        assert!((growth.unwrap() - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_falls_back_to_annual_cagr() {
+        let annual = vec![
+            report("2024-12-31", Some(121)),
+            report("2023-12-31", Some(110)),
+            report("2022-12-31", Some(100)),
+        ];
+
+        let growth = revenue_growth_rate(&[], &annual);
+        assert!((growth.unwrap() - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_none_sentinel_deserializes_to_missing() {
+        let json = serde_json::json!({
+            "fiscalDateEnding": "2024-12-31",
+            "totalRevenue": "None"
+        });
+
+        let report: IncomeStatementReport = serde_json::from_value(json).unwrap();
+        assert_eq!(report.total_revenue, None);
+    }
+}
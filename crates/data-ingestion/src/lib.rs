@@ -0,0 +1,19 @@
+// Data ingestion crate - vendor market data clients behind a common provider trait
+
+pub mod aggregates;
+pub mod cache;
+pub mod fundamentals;
+pub mod oauth_provider;
+pub mod polygon;
+pub mod price_history;
+pub mod provider;
+pub mod rate_limiter;
+
+pub use aggregates::{backfill_and_resample, resample, BackfillResult, ResamplePeriod};
+pub use cache::TickerCache;
+pub use fundamentals::{AlphaVantageClient, EarningsData, FundamentalsProvider, IncomeStatementData};
+pub use oauth_provider::{AuthenticationInfo, OAuthMarketDataProvider};
+pub use polygon::PolygonClient;
+pub use price_history::{CsvPriceHistoryProvider, PriceHistoryProvider};
+pub use provider::MarketDataProvider;
+pub use rate_limiter::RateLimiter;
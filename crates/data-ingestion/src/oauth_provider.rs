@@ -0,0 +1,213 @@
+// OAuth/token-refresh market data provider, for vendors that front their feed with a
+// standard access/refresh token protocol instead of a static API key
+
+use crate::polygon::{AggregateBar, Financial, TickerDetails, TickerSearchResult};
+use crate::provider::MarketDataProvider;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+/// Access token, refresh token, and expiry for an OAuth-protected data feed
+#[derive(Debug, Clone)]
+pub struct AuthenticationInfo {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl AuthenticationInfo {
+    fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: i64,
+}
+
+/// A `MarketDataProvider` backed by an OAuth/token-refresh protocol: keeps a `Client` plus
+/// refreshable `AuthenticationInfo`, transparently re-authenticating and retrying a request
+/// once on a 401 before bubbling the error up.
+pub struct OAuthMarketDataProvider {
+    base_url: String,
+    client_id: String,
+    client_secret: String,
+    http: Client,
+    auth: Arc<RwLock<AuthenticationInfo>>,
+}
+
+impl OAuthMarketDataProvider {
+    pub fn new(
+        base_url: String,
+        client_id: String,
+        client_secret: String,
+        auth: AuthenticationInfo,
+    ) -> Self {
+        Self {
+            base_url,
+            client_id,
+            client_secret,
+            http: Client::new(),
+            auth: Arc::new(RwLock::new(auth)),
+        }
+    }
+
+    /// Exchange the stored refresh token for a new access/refresh token pair
+    async fn refresh(&self) -> Result<()> {
+        let refresh_token = self.auth.read().await.refresh_token.clone();
+
+        let response = self
+            .http
+            .post(format!("{}/oauth/token", self.base_url))
+            .form(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token.as_str()),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+            ])
+            .send()
+            .await
+            .context("Token refresh request failed")?
+            .error_for_status()
+            .context("Token refresh rejected")?
+            .json::<TokenResponse>()
+            .await
+            .context("Failed to parse token refresh response")?;
+
+        let mut auth = self.auth.write().await;
+        auth.access_token = response.access_token;
+        auth.refresh_token = response.refresh_token;
+        auth.expires_at = Utc::now() + Duration::seconds(response.expires_in);
+
+        Ok(())
+    }
+
+    /// GET `path` with the current access token, refreshing first if it's already expired
+    /// and retrying once (after a fresh refresh) if the server still returns a 401
+    async fn authenticated_get(&self, path: &str) -> Result<serde_json::Value> {
+        if self.auth.read().await.is_expired() {
+            self.refresh().await?;
+        }
+
+        let url = format!("{}{}", self.base_url, path);
+        let token = self.auth.read().await.access_token.clone();
+        let response = self
+            .http
+            .get(&url)
+            .bearer_auth(&token)
+            .send()
+            .await
+            .context("Request failed")?;
+
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return response
+                .error_for_status()
+                .context("Request rejected")?
+                .json::<serde_json::Value>()
+                .await
+                .context("Failed to parse response");
+        }
+
+        warn!("Access token rejected for {}, refreshing and retrying once", path);
+        self.refresh().await?;
+        let token = self.auth.read().await.access_token.clone();
+
+        self.http
+            .get(&url)
+            .bearer_auth(&token)
+            .send()
+            .await
+            .context("Retry after token refresh failed")?
+            .error_for_status()
+            .context("Request rejected after token refresh")?
+            .json::<serde_json::Value>()
+            .await
+            .context("Failed to parse response")
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for OAuthMarketDataProvider {
+    async fn get_ticker_details(&self, ticker: &str) -> Result<TickerDetails> {
+        let json = self
+            .authenticated_get(&format!("/v1/tickers/{}", ticker))
+            .await?;
+        serde_json::from_value(json).context("Failed to parse ticker details response")
+    }
+
+    async fn get_aggregates(
+        &self,
+        ticker: &str,
+        multiplier: u32,
+        timespan: &str,
+        from_date: Option<NaiveDate>,
+        to_date: Option<NaiveDate>,
+        limit: u32,
+    ) -> Result<Vec<AggregateBar>> {
+        let from = from_date
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let to = to_date
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let path = format!(
+            "/v1/aggregates/{}/{}/{}?from={}&to={}&limit={}",
+            ticker, multiplier, timespan, from, to, limit
+        );
+
+        let json = self.authenticated_get(&path).await?;
+        serde_json::from_value(json).context("Failed to parse aggregates response")
+    }
+
+    async fn get_financials(
+        &self,
+        ticker: &str,
+        timeframe: &str,
+        limit: u32,
+    ) -> Result<Vec<Financial>> {
+        let path = format!(
+            "/v1/financials/{}?timeframe={}&limit={}",
+            ticker, timeframe, limit
+        );
+
+        let json = self.authenticated_get(&path).await?;
+        serde_json::from_value(json).context("Failed to parse financials response")
+    }
+
+    async fn get_market_cap(&self, ticker: &str) -> Result<Option<i64>> {
+        match self.get_ticker_details(ticker).await {
+            Ok(details) => Ok(details.market_cap),
+            Err(e) => {
+                warn!("Error fetching market cap for {}: {}", ticker, e);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn search_tickers(
+        &self,
+        market: Option<&str>,
+        exchange: Option<&str>,
+        active: bool,
+        limit: u32,
+    ) -> Result<Vec<TickerSearchResult>> {
+        let mut path = format!("/v1/tickers?active={}&limit={}", active, limit);
+        if let Some(m) = market {
+            path.push_str(&format!("&market={}", m));
+        }
+        if let Some(ex) = exchange {
+            path.push_str(&format!("&exchange={}", ex));
+        }
+
+        let json = self.authenticated_get(&path).await?;
+        serde_json::from_value(json).context("Failed to parse search response")
+    }
+}
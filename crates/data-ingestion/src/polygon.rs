@@ -1,6 +1,7 @@
 // Polygon.io API client (ported from Python prototype)
 
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use chrono::{Duration, NaiveDate, Utc};
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
@@ -9,15 +10,24 @@ use std::time::Duration as StdDuration;
 use tokio::time::sleep;
 use tracing::{debug, warn};
 
+use crate::cache::TickerCache;
+use crate::provider::MarketDataProvider;
+use crate::rate_limiter::RateLimiter;
+use std::sync::Arc;
+
 const BASE_URL: &str = "https://api.polygon.io";
-const RATE_LIMIT_DELAY_MS: u64 = 200; // Conservative 200ms delay
+// Polygon's free tier allows 5 requests/min; paid tiers can raise this via env vars
+const DEFAULT_RATE_LIMIT_CAPACITY: f64 = 5.0;
+const DEFAULT_RATE_LIMIT_PER_MIN: f64 = 5.0;
+const DEFAULT_TICKER_CACHE_TTL: StdDuration = StdDuration::from_secs(300);
 const MAX_RETRIES: u32 = 3;
 
 #[derive(Clone)]
 pub struct PolygonClient {
     api_key: String,
     client: Client,
-    rate_limit_delay: StdDuration,
+    rate_limiter: RateLimiter,
+    ticker_cache: Arc<TickerCache>,
 }
 
 // Response types
@@ -118,20 +128,45 @@ pub struct FinancialValue {
 }
 
 impl PolygonClient {
-    /// Create a new Polygon client with API key from environment or parameter
+    /// Create a new Polygon client with API key from environment or parameter, caching
+    /// ticker details for `DEFAULT_TICKER_CACHE_TTL`
     pub fn new(api_key: Option<String>) -> Result<Self> {
+        Self::with_cache(api_key, DEFAULT_TICKER_CACHE_TTL)
+    }
+
+    /// Like `new`, but with an explicit ticker-details cache TTL - useful when a batch run
+    /// (e.g. `classify_batch`, `update_fundamentals`) wants every cloned worker to share one
+    /// cache warmed for the duration of the run
+    pub fn with_cache(api_key: Option<String>, ticker_cache_ttl: StdDuration) -> Result<Self> {
         let api_key = api_key
             .or_else(|| std::env::var("POLYGON_API_KEY").ok())
             .context("POLYGON_API_KEY must be set in environment or passed to constructor")?;
 
+        let capacity = std::env::var("POLYGON_RATE_LIMIT_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_RATE_LIMIT_CAPACITY);
+        let refill_per_min = std::env::var("POLYGON_RATE_LIMIT_PER_MIN")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_RATE_LIMIT_PER_MIN);
+
         Ok(Self {
             api_key,
             client: Client::new(),
-            rate_limit_delay: StdDuration::from_millis(RATE_LIMIT_DELAY_MS),
+            rate_limiter: RateLimiter::new(capacity, refill_per_min / 60.0),
+            ticker_cache: Arc::new(TickerCache::new(ticker_cache_ttl)),
         })
     }
 
-    /// Make API request with retry logic and rate limiting
+    /// Drop any cached details for `ticker`, forcing the next lookup to hit the network
+    pub fn invalidate_ticker_cache(&self, ticker: &str) {
+        self.ticker_cache.invalidate(ticker);
+    }
+
+    /// Make API request with retry logic and rate limiting. `rate_limiter` is shared across
+    /// every clone of this client, so concurrent workers draw from one combined quota
+    /// instead of each pacing its own fixed delay.
     async fn make_request(
         &self,
         endpoint: &str,
@@ -143,8 +178,7 @@ impl PolygonClient {
 
         let mut retries = 0;
         loop {
-            // Rate limiting
-            sleep(self.rate_limit_delay).await;
+            self.rate_limiter.acquire().await;
 
             debug!("Making request to: {}", endpoint);
 
@@ -204,14 +238,22 @@ impl PolygonClient {
         }
     }
 
-    /// Get detailed information about a ticker
+    /// Get detailed information about a ticker, serving from the ticker cache on a hit and
+    /// only calling `make_request` on a miss or expiry
     pub async fn get_ticker_details(&self, ticker: &str) -> Result<TickerDetails> {
+        if let Some(cached) = self.ticker_cache.get(ticker) {
+            return Ok(cached);
+        }
+
         let endpoint = format!("/v3/reference/tickers/{}", ticker);
         let json = self.make_request(&endpoint, None).await?;
 
         let response: TickerDetailsResponse =
             serde_json::from_value(json).context("Failed to parse ticker details response")?;
 
+        self.ticker_cache
+            .insert(ticker.to_string(), response.results.clone());
+
         Ok(response.results)
     }
 
@@ -343,6 +385,81 @@ impl PolygonClient {
 
         Some(((latest_revenue - previous_revenue) / previous_revenue) * 100.0)
     }
+
+    /// Compound annual revenue growth rate over `years` reporting periods (e.g. 3 annual
+    /// financials apart), as a percentage. `financials` must be ordered newest-first, matching
+    /// what `get_financials` returns.
+    pub fn calculate_revenue_cagr(financials: &[Financial], years: u32) -> Option<f32> {
+        if years == 0 || financials.len() <= years as usize {
+            return None;
+        }
+
+        let latest = financials.first()?;
+        let oldest = financials.get(years as usize)?;
+
+        let latest_revenue = latest
+            .financials
+            .as_ref()?
+            .income_statement
+            .as_ref()?
+            .revenues
+            .as_ref()?
+            .value? as f32;
+
+        let oldest_revenue = oldest
+            .financials
+            .as_ref()?
+            .income_statement
+            .as_ref()?
+            .revenues
+            .as_ref()?
+            .value? as f32;
+
+        if oldest_revenue <= 0.0 || latest_revenue <= 0.0 {
+            return None;
+        }
+
+        let cagr = (latest_revenue / oldest_revenue).powf(1.0 / years as f32) - 1.0;
+        Some(cagr * 100.0)
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for PolygonClient {
+    async fn get_ticker_details(&self, ticker: &str) -> Result<TickerDetails> {
+        PolygonClient::get_ticker_details(self, ticker).await
+    }
+
+    async fn get_aggregates(
+        &self,
+        ticker: &str,
+        multiplier: u32,
+        timespan: &str,
+        from_date: Option<NaiveDate>,
+        to_date: Option<NaiveDate>,
+        limit: u32,
+    ) -> Result<Vec<AggregateBar>> {
+        PolygonClient::get_aggregates(self, ticker, multiplier, timespan, from_date, to_date, limit)
+            .await
+    }
+
+    async fn get_financials(&self, ticker: &str, timeframe: &str, limit: u32) -> Result<Vec<Financial>> {
+        PolygonClient::get_financials(self, ticker, timeframe, limit).await
+    }
+
+    async fn get_market_cap(&self, ticker: &str) -> Result<Option<i64>> {
+        PolygonClient::get_market_cap(self, ticker).await
+    }
+
+    async fn search_tickers(
+        &self,
+        market: Option<&str>,
+        exchange: Option<&str>,
+        active: bool,
+        limit: u32,
+    ) -> Result<Vec<TickerSearchResult>> {
+        PolygonClient::search_tickers(self, market, exchange, active, limit).await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,162 @@
+// Daily adjusted-close price history, for reconstructing backtests - behind a
+// vendor-neutral `PriceHistoryProvider` trait so a backtest isn't hardwired to one source.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::fundamentals::AlphaVantageClient;
+
+const TIME_SERIES_FUNCTION: &str = "TIME_SERIES_DAILY_ADJUSTED";
+
+/// A source of daily adjusted-close price history for a ticker
+#[async_trait]
+pub trait PriceHistoryProvider: Send + Sync {
+    /// Daily adjusted close, keyed by date, restricted to `[from, to]`
+    async fn get_daily_adjusted_close(
+        &self,
+        ticker: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, f64>>;
+}
+
+#[derive(Debug, Deserialize)]
+struct DailyAdjustedResponse {
+    #[serde(rename = "Time Series (Daily)")]
+    time_series: HashMap<String, DailyAdjustedBar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DailyAdjustedBar {
+    #[serde(rename = "5. adjusted close")]
+    adjusted_close: String,
+}
+
+#[async_trait]
+impl PriceHistoryProvider for AlphaVantageClient {
+    async fn get_daily_adjusted_close(
+        &self,
+        ticker: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, f64>> {
+        // "full" covers 20+ years of history; Alpha Vantage defaults to the last 100 days
+        // otherwise, which would silently truncate anything but the shortest backtests
+        let json = self
+            .fetch_with_params(
+                TIME_SERIES_FUNCTION,
+                ticker,
+                &[("outputsize", "full")],
+            )
+            .await?;
+
+        let response: DailyAdjustedResponse = serde_json::from_value(json)
+            .context("Failed to parse TIME_SERIES_DAILY_ADJUSTED response")?;
+
+        let mut series = BTreeMap::new();
+        for (date_str, bar) in response.time_series {
+            let Ok(date) = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") else {
+                continue;
+            };
+            if date < from || date > to {
+                continue;
+            }
+            if let Ok(adjusted_close) = bar.adjusted_close.parse::<f64>() {
+                series.insert(date, adjusted_close);
+            }
+        }
+
+        Ok(series)
+    }
+}
+
+/// A `PriceHistoryProvider` backed by a local CSV file, for backtests run offline or over
+/// tickers Alpha Vantage doesn't cover. Expects a header row and `ticker,date,adjusted_close`
+/// columns (date as `YYYY-MM-DD`).
+pub struct CsvPriceHistoryProvider {
+    prices: HashMap<String, BTreeMap<NaiveDate, f64>>,
+}
+
+impl CsvPriceHistoryProvider {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).context("Failed to read price history CSV")?;
+
+        let mut prices: HashMap<String, BTreeMap<NaiveDate, f64>> = HashMap::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            if i == 0 && line.to_lowercase().contains("ticker") {
+                continue;
+            }
+
+            let parts: Vec<&str> = line.split(',').collect();
+            if parts.len() < 3 {
+                continue;
+            }
+
+            let ticker = parts[0].trim().to_string();
+            let Ok(date) = NaiveDate::parse_from_str(parts[1].trim(), "%Y-%m-%d") else {
+                continue;
+            };
+            let Ok(adjusted_close) = parts[2].trim().parse::<f64>() else {
+                continue;
+            };
+
+            prices.entry(ticker).or_default().insert(date, adjusted_close);
+        }
+
+        Ok(Self { prices })
+    }
+}
+
+#[async_trait]
+impl PriceHistoryProvider for CsvPriceHistoryProvider {
+    async fn get_daily_adjusted_close(
+        &self,
+        ticker: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, f64>> {
+        let series = self
+            .prices
+            .get(ticker)
+            .map(|series| {
+                series
+                    .range(from..=to)
+                    .map(|(&date, &price)| (date, price))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(series)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_csv_provider_filters_to_range() {
+        let csv = "ticker,date,adjusted_close\nRKLB,2024-01-02,10.0\nRKLB,2024-01-03,11.0\nRKLB,2024-06-01,20.0\n";
+        let path = std::env::temp_dir().join("future-economy-indices-test-prices.csv");
+        std::fs::write(&path, csv).unwrap();
+
+        let provider = CsvPriceHistoryProvider::load(path.to_str().unwrap()).unwrap();
+        let series = provider
+            .get_daily_adjusted_close(
+                "RKLB",
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[&NaiveDate::from_ymd_opt(2024, 1, 2).unwrap()], 10.0);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,41 @@
+// Vendor-neutral market data provider abstraction
+
+use crate::polygon::{AggregateBar, Financial, TickerDetails, TickerSearchResult};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+/// A source of ticker reference data, price history, and financials. `PolygonClient` is
+/// the default implementation; other vendors can plug in behind this trait so ingestion
+/// code isn't hardwired to a single vendor and the CLI/server can select one by config.
+#[async_trait]
+pub trait MarketDataProvider: Send + Sync {
+    /// Get detailed information about a ticker
+    async fn get_ticker_details(&self, ticker: &str) -> Result<TickerDetails>;
+
+    /// Get aggregate bars (OHLCV) for a ticker
+    async fn get_aggregates(
+        &self,
+        ticker: &str,
+        multiplier: u32,
+        timespan: &str,
+        from_date: Option<NaiveDate>,
+        to_date: Option<NaiveDate>,
+        limit: u32,
+    ) -> Result<Vec<AggregateBar>>;
+
+    /// Get financial data (income statement, balance sheet, cash flow)
+    async fn get_financials(&self, ticker: &str, timeframe: &str, limit: u32) -> Result<Vec<Financial>>;
+
+    /// Get current market capitalization for a ticker
+    async fn get_market_cap(&self, ticker: &str) -> Result<Option<i64>>;
+
+    /// Search for tickers matching criteria
+    async fn search_tickers(
+        &self,
+        market: Option<&str>,
+        exchange: Option<&str>,
+        active: bool,
+        limit: u32,
+    ) -> Result<Vec<TickerSearchResult>>;
+}
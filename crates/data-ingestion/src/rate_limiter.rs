@@ -0,0 +1,60 @@
+// Shared token-bucket rate limiter, so concurrently cloned clients collectively respect a
+// single request-rate quota instead of each client independently pacing its own delay
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token-bucket limiter: `capacity` tokens, refilling at `refill_per_sec` tokens/sec.
+/// Cheap to `clone` - clones share the same underlying bucket via `Arc`, so every clone of
+/// a rate-limited client draws from the same quota.
+#[derive(Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+impl RateLimiter {
+    pub fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            })),
+            capacity,
+            refill_per_sec,
+        }
+    }
+
+    /// Block until a token is available, then consume it
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let elapsed = bucket.last_refill.elapsed().as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                bucket.last_refill = Instant::now();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - bucket.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_per_sec))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(delay) => sleep(delay).await,
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Postgres LISTEN/NOTIFY change feed for index updates.
+//
+// `insert_index_performance` and `commit_rebalance` fire `pg_notify('index_events', ...)` so
+// external consumers (dashboards, the newsletter job) can react to new data as it lands instead
+// of polling `get_latest_index_performance` on a timer.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use futures::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgListener;
+use std::time::Duration;
+use tracing::warn;
+
+/// The `LISTEN`/`NOTIFY` channel index events are published on
+pub const INDEX_EVENTS_CHANNEL: &str = "index_events";
+
+/// How long to wait before retrying a dropped or failed listener connection
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// A single index-related event delivered over `index_events`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEvent {
+    pub index_name: String,
+    pub event_type: String,
+    pub date: NaiveDate,
+}
+
+impl IndexEvent {
+    pub fn new(index_name: impl Into<String>, event_type: impl Into<String>, date: NaiveDate) -> Self {
+        Self {
+            index_name: index_name.into(),
+            event_type: event_type.into(),
+            date,
+        }
+    }
+}
+
+/// Fire `pg_notify(INDEX_EVENTS_CHANNEL, ...)` with `event` as its JSON payload. Takes any sqlx
+/// executor so it can run standalone (a plain `&PgPool`) or as part of an in-flight transaction
+/// (`&mut Transaction<'_, Postgres>`), matching whichever caller is publishing the event.
+pub(crate) async fn notify_index_event<'e, E>(executor: E, event: &IndexEvent) -> Result<()>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let payload = serde_json::to_string(event)?;
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(INDEX_EVENTS_CHANNEL)
+        .bind(payload)
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+/// Subscribe to `index_events`, optionally filtered to one index, yielding deserialized events
+/// as they arrive. Opens its own dedicated connection (`LISTEN`/`NOTIFY` isn't meaningful over a
+/// pooled connection shared with other queries) and transparently reconnects if that connection
+/// drops, so callers can treat the stream as never-ending.
+pub fn subscribe_index_events(
+    database_url: &str,
+    index_name: Option<&str>,
+) -> impl Stream<Item = IndexEvent> {
+    let database_url = database_url.to_string();
+    let index_name = index_name.map(|s| s.to_string());
+
+    stream::unfold(
+        (database_url, index_name, None::<PgListener>),
+        |(database_url, index_name, mut listener)| async move {
+            loop {
+                if listener.is_none() {
+                    listener = match connect_listener(&database_url).await {
+                        Ok(listener) => Some(listener),
+                        Err(e) => {
+                            warn!("Failed to (re)connect index_events listener: {}", e);
+                            tokio::time::sleep(RECONNECT_DELAY).await;
+                            continue;
+                        }
+                    };
+                }
+
+                let notification = match listener.as_mut().unwrap().try_recv().await {
+                    Ok(Some(notification)) => notification,
+                    Ok(None) => {
+                        // Listener's connection was closed cleanly - reconnect and keep going
+                        listener = None;
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!("index_events listener connection dropped: {}", e);
+                        listener = None;
+                        continue;
+                    }
+                };
+
+                let event: IndexEvent = match serde_json::from_str(notification.payload()) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!("Failed to parse index event payload: {}", e);
+                        continue;
+                    }
+                };
+
+                if let Some(filter) = &index_name {
+                    if &event.index_name != filter {
+                        continue;
+                    }
+                }
+
+                return Some((event, (database_url, index_name, listener)));
+            }
+        },
+    )
+}
+
+async fn connect_listener(database_url: &str) -> Result<PgListener> {
+    let mut listener = PgListener::connect(database_url).await?;
+    listener.listen(INDEX_EVENTS_CHANNEL).await?;
+    Ok(listener)
+}
@@ -4,23 +4,33 @@ use anyhow::Result;
 use sqlx::{postgres::PgPoolOptions, PgPool};
 use std::time::Duration;
 
+pub mod events;
+pub mod migrations;
 pub mod models;
 pub mod schema;
 
+pub use events::{subscribe_index_events, IndexEvent, INDEX_EVENTS_CHANNEL};
+pub use migrations::{migrate, migration_status, rollback, AppliedMigration, MigrationStatus};
 pub use models::*;
 pub use schema::{
     // Company queries
     get_all_companies,
     get_companies_by_space_score,
+    get_companies_filtered,
     get_company_by_ticker,
     upsert_company,
     // Fundamental queries
     get_fundamentals_by_company,
     get_latest_fundamental,
     insert_fundamental,
+    upsert_fundamental,
     // Index composition queries
+    commit_rebalance,
+    diff_rebalances,
     get_current_index_composition,
     get_index_composition_as_of,
+    get_index_composition_with_companies_as_of,
+    get_index_composition_screened,
     get_index_composition_with_companies,
     get_index_rebalance_dates,
     insert_index_composition,
@@ -30,9 +40,26 @@ pub use schema::{
     insert_index_performance,
     // Metadata queries
     get_index_metadata,
+    // Database stats queries
+    get_database_stats,
+    // Index registry queries
+    get_all_index_definitions,
+    get_index_definition,
+    // Corporate action queries
+    get_dividends_for_tickers,
+    get_splits_for_tickers,
+    insert_dividend,
+    insert_split,
     // Types
+    CompanyFilter,
+    CompositionScreenFilter,
     CompositionWithCompany,
+    DatabaseStats,
     IndexMetadata,
+    Page,
+    RebalanceDiff,
+    TableRowCount,
+    WeightDelta,
 };
 
 /// Initialize database connection pool
@@ -44,11 +71,3 @@ pub async fn init_pool(database_url: &str) -> Result<PgPool> {
         .await?;
     Ok(pool)
 }
-
-/// Run database migrations
-pub async fn run_migrations(pool: &PgPool) -> Result<()> {
-    sqlx::migrate!("./migrations")
-        .run(pool)
-        .await?;
-    Ok(())
-}
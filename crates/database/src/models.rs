@@ -56,3 +56,24 @@ pub struct IndexPerformance {
     pub daily_return: Option<f32>,
     pub created_at: DateTime<Utc>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CorporateAction {
+    pub id: i32,
+    pub ticker: String,
+    pub ex_date: NaiveDate,
+    pub action_type: String,
+    pub amount_per_share: Option<f32>,
+    pub split_ratio: Option<f32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct IndexRegistryEntry {
+    pub index_name: String,
+    pub display_name: String,
+    pub description: String,
+    pub inception_date: NaiveDate,
+    pub rebalance_frequency_months: i32,
+    pub created_at: DateTime<Utc>,
+}
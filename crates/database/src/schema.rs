@@ -2,9 +2,13 @@
 
 use anyhow::Result;
 use chrono::NaiveDate;
-use sqlx::PgPool;
+use sqlx::{PgPool, QueryBuilder};
+use tracing::warn;
 
-use crate::models::{Company, Fundamental, IndexComposition, IndexPerformance};
+use crate::events::{notify_index_event, IndexEvent};
+use crate::models::{
+    Company, CorporateAction, Fundamental, IndexComposition, IndexPerformance, IndexRegistryEntry,
+};
 
 // ============================================================================
 // Company Queries
@@ -97,6 +101,113 @@ pub async fn get_companies_by_space_score(pool: &PgPool, min_score: f32) -> Resu
     Ok(companies)
 }
 
+/// Composable filter/sort/pagination options for screening the universe of companies across
+/// several axes at once (space score, AI score, market cap, segment, and revenue growth pulled
+/// from each company's latest `fundamentals` row) - the same "fragment accumulator" shape as
+/// `CompositionScreenFilter` below, applied to the companies table instead of one index's
+/// current composition.
+#[derive(Debug, Clone, Default)]
+pub struct CompanyFilter {
+    pub min_space_score: Option<f32>,
+    pub min_ai_score: Option<f32>,
+    pub min_market_cap: Option<i64>,
+    pub max_market_cap: Option<i64>,
+    pub segment: Option<String>,
+    pub min_revenue_growth_yoy: Option<f32>,
+    pub sort_by: Option<String>,
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+const DEFAULT_COMPANY_PAGE_LIMIT: i64 = 50;
+
+/// A page of rows plus the total number of rows that matched the filter (ignoring pagination),
+/// so callers can render e.g. "showing 1-50 of 340" without a second round trip
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub rows: Vec<T>,
+    pub total: i64,
+}
+
+/// Append `filter`'s predicates (without a leading `WHERE`) - shared between the `COUNT(*)`
+/// query and the row-fetching query in `get_companies_filtered` so both stay in sync
+fn push_company_filter(query: &mut QueryBuilder<'_, sqlx::Postgres>, filter: &CompanyFilter) {
+    query.push(" WHERE 1=1");
+
+    if let Some(min_space_score) = filter.min_space_score {
+        query.push(" AND c.space_score >= ").push_bind(min_space_score);
+    }
+    if let Some(min_ai_score) = filter.min_ai_score {
+        query.push(" AND c.ai_score >= ").push_bind(min_ai_score);
+    }
+    if let Some(min_market_cap) = filter.min_market_cap {
+        query.push(" AND c.market_cap >= ").push_bind(min_market_cap);
+    }
+    if let Some(max_market_cap) = filter.max_market_cap {
+        query.push(" AND c.market_cap <= ").push_bind(max_market_cap);
+    }
+    if let Some(segment) = &filter.segment {
+        query.push(" AND c.segments @> ARRAY[").push_bind(segment).push("]");
+    }
+    if let Some(min_revenue_growth_yoy) = filter.min_revenue_growth_yoy {
+        query
+            .push(" AND latest_fundamental.revenue_growth_yoy >= ")
+            .push_bind(min_revenue_growth_yoy);
+    }
+}
+
+/// Screen the companies table against a `CompanyFilter`'s accumulated predicates, returning a
+/// `Page` of matching rows (sorted and paginated) plus the total match count from a companion
+/// `COUNT(*)` query run against the same predicates. Replaces one-axis-at-a-time functions like
+/// `get_companies_by_space_score` with a single flexible screening API.
+pub async fn get_companies_filtered(pool: &PgPool, filter: &CompanyFilter) -> Result<Page<Company>> {
+    const LATEST_FUNDAMENTAL_JOIN: &str = r#"
+        LEFT JOIN LATERAL (
+            SELECT revenue_growth_yoy FROM fundamentals f
+            WHERE f.company_id = c.id
+            ORDER BY f.date DESC
+            LIMIT 1
+        ) latest_fundamental ON true
+    "#;
+
+    let mut count_query = QueryBuilder::new(format!(
+        "SELECT COUNT(*) FROM companies c {}",
+        LATEST_FUNDAMENTAL_JOIN
+    ));
+    push_company_filter(&mut count_query, filter);
+    let total: i64 = count_query.build_query_scalar().fetch_one(pool).await?;
+
+    let mut query = QueryBuilder::new(format!(
+        r#"
+        SELECT c.id, c.ticker, c.name, c.description, c.market_cap, c.space_score, c.ai_score,
+               c.segments, c.last_classified_at, c.created_at, c.updated_at
+        FROM companies c
+        {}
+        "#,
+        LATEST_FUNDAMENTAL_JOIN
+    ));
+    push_company_filter(&mut query, filter);
+
+    let sort_column = match filter.sort_by.as_deref() {
+        Some("ai_score") => "c.ai_score",
+        Some("market_cap") => "c.market_cap",
+        _ => "c.space_score",
+    };
+    let sort_direction = match filter.order.as_deref() {
+        Some("asc") => "ASC",
+        _ => "DESC",
+    };
+    query.push(format!(" ORDER BY {} {} LIMIT ", sort_column, sort_direction));
+    query.push_bind(filter.limit.unwrap_or(DEFAULT_COMPANY_PAGE_LIMIT));
+    query.push(" OFFSET ");
+    query.push_bind(filter.offset.unwrap_or(0));
+
+    let rows = query.build_query_as::<Company>().fetch_all(pool).await?;
+
+    Ok(Page { rows, total })
+}
+
 // ============================================================================
 // Fundamental Queries
 // ============================================================================
@@ -127,6 +238,153 @@ pub async fn insert_fundamental(pool: &PgPool, fundamental: &Fundamental) -> Res
     Ok(row.id)
 }
 
+/// Insert or update a fundamental row, keyed on `(company_id, date)`. Safe to call with
+/// records that arrive out of chronological order or over overlapping ranges: a record
+/// only overwrites what's stored when it's at least as complete, and any
+/// `revenue_growth_yoy`/`revenue_growth_3y_cagr` left unset is computed from the stored
+/// neighbor rows for that company rather than assumed from insertion order.
+pub async fn upsert_fundamental(pool: &PgPool, incoming: &Fundamental) -> Result<i32> {
+    let mut merged = incoming.clone();
+
+    if merged.revenue_growth_yoy.is_none() {
+        if let (Some(revenue), Some(prior)) = (
+            merged.revenue,
+            nearest_fundamental(pool, merged.company_id, merged.date, 1).await?,
+        ) {
+            if let Some(prior_revenue) = prior.revenue {
+                merged.revenue_growth_yoy = percent_change(prior_revenue, revenue);
+            }
+        }
+    }
+
+    if merged.revenue_growth_3y_cagr.is_none() {
+        if let (Some(revenue), Some(prior)) = (
+            merged.revenue,
+            nearest_fundamental(pool, merged.company_id, merged.date, 3).await?,
+        ) {
+            if let Some(prior_revenue) = prior.revenue {
+                merged.revenue_growth_3y_cagr = cagr(prior_revenue, revenue, 3.0);
+            }
+        }
+    }
+
+    let existing = sqlx::query_as!(
+        Fundamental,
+        r#"
+        SELECT id as "id!", company_id as "company_id!", date, revenue, revenue_growth_yoy, revenue_growth_3y_cagr,
+               market_cap, price, volume, created_at
+        FROM fundamentals
+        WHERE company_id = $1 AND date = $2
+        "#,
+        merged.company_id,
+        merged.date
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(existing) = &existing {
+        if fundamental_completeness(&merged) < fundamental_completeness(existing) {
+            return Ok(existing.id);
+        }
+    }
+
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO fundamentals (
+            company_id, date, revenue, revenue_growth_yoy, revenue_growth_3y_cagr,
+            market_cap, price, volume
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (company_id, date)
+        DO UPDATE SET
+            revenue = EXCLUDED.revenue,
+            revenue_growth_yoy = EXCLUDED.revenue_growth_yoy,
+            revenue_growth_3y_cagr = EXCLUDED.revenue_growth_3y_cagr,
+            market_cap = EXCLUDED.market_cap,
+            price = EXCLUDED.price,
+            volume = EXCLUDED.volume
+        RETURNING id
+        "#,
+        merged.company_id,
+        merged.date,
+        merged.revenue,
+        merged.revenue_growth_yoy,
+        merged.revenue_growth_3y_cagr,
+        merged.market_cap,
+        merged.price,
+        merged.volume
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.id)
+}
+
+/// The fundamental row for `company_id` nearest `years_ago` years before `date`, within a
+/// 45-day window either side (fundamentals land on filing dates, not exact anniversaries)
+async fn nearest_fundamental(
+    pool: &PgPool,
+    company_id: i32,
+    date: NaiveDate,
+    years_ago: i64,
+) -> Result<Option<Fundamental>> {
+    let target = date - chrono::Duration::days(365 * years_ago);
+    let window_start = target - chrono::Duration::days(45);
+    let window_end = target + chrono::Duration::days(45);
+
+    let neighbor = sqlx::query_as!(
+        Fundamental,
+        r#"
+        SELECT id as "id!", company_id as "company_id!", date, revenue, revenue_growth_yoy, revenue_growth_3y_cagr,
+               market_cap, price, volume, created_at
+        FROM fundamentals
+        WHERE company_id = $1 AND date BETWEEN $2 AND $3
+        ORDER BY abs(date - $4)
+        LIMIT 1
+        "#,
+        company_id,
+        window_start,
+        window_end,
+        target
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(neighbor)
+}
+
+/// Year-over-year percent change from `from` to `to`
+fn percent_change(from: i64, to: i64) -> Option<f32> {
+    if from == 0 {
+        return None;
+    }
+    Some((to - from) as f32 / from as f32 * 100.0)
+}
+
+/// Compound annual growth rate from `from` to `to` over `years` years, as a percent
+fn cagr(from: i64, to: i64, years: f32) -> Option<f32> {
+    if from <= 0 || to <= 0 {
+        return None;
+    }
+    Some(((to as f32 / from as f32).powf(1.0 / years) - 1.0) * 100.0)
+}
+
+/// Count of populated optional fields, used to decide whether an incoming record is
+/// complete enough to overwrite what's already stored
+fn fundamental_completeness(fundamental: &Fundamental) -> u8 {
+    [
+        fundamental.revenue.is_some(),
+        fundamental.revenue_growth_yoy.is_some(),
+        fundamental.revenue_growth_3y_cagr.is_some(),
+        fundamental.market_cap.is_some(),
+        fundamental.price.is_some(),
+        fundamental.volume.is_some(),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count() as u8
+}
+
 /// Get latest fundamental for a company
 pub async fn get_latest_fundamental(pool: &PgPool, company_id: i32) -> Result<Option<Fundamental>> {
     let fundamental = sqlx::query_as!(
@@ -176,6 +434,78 @@ pub async fn get_fundamentals_by_company(
 // Index Composition Queries
 // ============================================================================
 
+/// Atomically replace an index's composition for one `(index_name, rebalance_date)` pair:
+/// deletes any prior rows for that exact pair, then bulk-inserts every constituent via a
+/// single multi-row `UNNEST` insert, and commits only if every row succeeds. Unlike calling
+/// `insert_index_composition` once per constituent, a failed rebalance can't leave
+/// `index_compositions` half-written - `get_current_index_composition` (keyed off
+/// `MAX(rebalance_date)`) would otherwise read that partial basket as current.
+pub async fn commit_rebalance(
+    pool: &PgPool,
+    index_name: &str,
+    rebalance_date: NaiveDate,
+    constituents: &[IndexComposition],
+) -> Result<usize> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM index_compositions WHERE index_name = $1 AND rebalance_date = $2",
+        index_name,
+        rebalance_date
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if constituents.is_empty() {
+        let event = IndexEvent::new(index_name, "rebalance", rebalance_date);
+        notify_index_event(&mut *tx, &event).await?;
+        tx.commit().await?;
+        return Ok(0);
+    }
+
+    let company_ids: Vec<i32> = constituents.iter().map(|c| c.company_id).collect();
+    let weights: Vec<f32> = constituents.iter().map(|c| c.weight).collect();
+    let ranks: Vec<Option<i32>> = constituents.iter().map(|c| c.rank).collect();
+    let space_revenue_pcts: Vec<Option<f32>> =
+        constituents.iter().map(|c| c.space_revenue_pct).collect();
+    let revenue_growth_rates: Vec<Option<f32>> =
+        constituents.iter().map(|c| c.revenue_growth_rate).collect();
+    let reasons: Vec<Option<String>> = constituents
+        .iter()
+        .map(|c| c.reason_included.clone())
+        .collect();
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO index_compositions (
+            index_name, company_id, weight, rebalance_date, rank,
+            space_revenue_pct, revenue_growth_rate, reason_included
+        )
+        SELECT $1, u.company_id, u.weight, $2, u.rank, u.space_revenue_pct,
+               u.revenue_growth_rate, u.reason_included
+        FROM UNNEST($3::int4[], $4::float4[], $5::int4[], $6::float4[], $7::float4[], $8::text[])
+            AS u(company_id, weight, rank, space_revenue_pct, revenue_growth_rate, reason_included)
+        "#,
+        index_name,
+        rebalance_date,
+        &company_ids,
+        &weights,
+        &ranks as &[Option<i32>],
+        &space_revenue_pcts as &[Option<f32>],
+        &revenue_growth_rates as &[Option<f32>],
+        &reasons as &[Option<String>],
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let event = IndexEvent::new(index_name, "rebalance", rebalance_date);
+    notify_index_event(&mut *tx, &event).await?;
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected() as usize)
+}
+
 /// Insert index composition
 pub async fn insert_index_composition(
     pool: &PgPool,
@@ -302,6 +632,11 @@ pub async fn insert_index_performance(
     .fetch_one(pool)
     .await?;
 
+    let event = IndexEvent::new(&performance.index_name, "performance_point", performance.date);
+    if let Err(e) = notify_index_event(pool, &event).await {
+        warn!("Failed to publish index_events notification: {}", e);
+    }
+
     Ok(row.id)
 }
 
@@ -354,12 +689,158 @@ pub async fn get_latest_index_performance(
     Ok(performance)
 }
 
+// ============================================================================
+// Corporate Action Queries
+// ============================================================================
+
+/// Record a dividend (ex-date, amount per share) for a ticker
+pub async fn insert_dividend(
+    pool: &PgPool,
+    ticker: &str,
+    ex_date: NaiveDate,
+    amount_per_share: f32,
+) -> Result<i32> {
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO corporate_actions (ticker, ex_date, action_type, amount_per_share)
+        VALUES ($1, $2, 'dividend', $3)
+        RETURNING id
+        "#,
+        ticker,
+        ex_date,
+        amount_per_share
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.id)
+}
+
+/// Record a stock split (ex-date, ratio) for a ticker
+pub async fn insert_split(
+    pool: &PgPool,
+    ticker: &str,
+    ex_date: NaiveDate,
+    ratio: f32,
+) -> Result<i32> {
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO corporate_actions (ticker, ex_date, action_type, split_ratio)
+        VALUES ($1, $2, 'split', $3)
+        RETURNING id
+        "#,
+        ticker,
+        ex_date,
+        ratio
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.id)
+}
+
+/// Get dividends for a set of tickers within a date range, ordered by ex-date
+pub async fn get_dividends_for_tickers(
+    pool: &PgPool,
+    tickers: &[String],
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> Result<Vec<CorporateAction>> {
+    let dividends = sqlx::query_as!(
+        CorporateAction,
+        r#"
+        SELECT id, ticker, ex_date, action_type, amount_per_share, split_ratio, created_at
+        FROM corporate_actions
+        WHERE action_type = 'dividend'
+        AND ticker = ANY($1)
+        AND ex_date >= $2 AND ex_date <= $3
+        ORDER BY ex_date ASC
+        "#,
+        tickers,
+        from_date,
+        to_date
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(dividends)
+}
+
+/// Get splits for a set of tickers within a date range, ordered by ex-date
+pub async fn get_splits_for_tickers(
+    pool: &PgPool,
+    tickers: &[String],
+    from_date: NaiveDate,
+    to_date: NaiveDate,
+) -> Result<Vec<CorporateAction>> {
+    let splits = sqlx::query_as!(
+        CorporateAction,
+        r#"
+        SELECT id, ticker, ex_date, action_type, amount_per_share, split_ratio, created_at
+        FROM corporate_actions
+        WHERE action_type = 'split'
+        AND ticker = ANY($1)
+        AND ex_date >= $2 AND ex_date <= $3
+        ORDER BY ex_date ASC
+        "#,
+        tickers,
+        from_date,
+        to_date
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(splits)
+}
+
+// ============================================================================
+// Index Registry Queries
+// ============================================================================
+
+/// Get all registered index definitions, ordered by inception date
+pub async fn get_all_index_definitions(pool: &PgPool) -> Result<Vec<IndexRegistryEntry>> {
+    let definitions = sqlx::query_as!(
+        IndexRegistryEntry,
+        r#"
+        SELECT index_name, display_name, description, inception_date,
+               rebalance_frequency_months, created_at
+        FROM index_registry
+        ORDER BY inception_date
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(definitions)
+}
+
+/// Get a single registered index definition by name
+pub async fn get_index_definition(
+    pool: &PgPool,
+    index_name: &str,
+) -> Result<Option<IndexRegistryEntry>> {
+    let definition = sqlx::query_as!(
+        IndexRegistryEntry,
+        r#"
+        SELECT index_name, display_name, description, inception_date,
+               rebalance_frequency_months, created_at
+        FROM index_registry
+        WHERE index_name = $1
+        "#,
+        index_name
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(definition)
+}
+
 // ============================================================================
 // Composite Queries (joining multiple tables)
 // ============================================================================
 
 /// Get index composition with company details
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CompositionWithCompany {
     pub ticker: String,
     pub company_name: String,
@@ -412,8 +893,220 @@ pub async fn get_index_composition_with_companies(
     Ok(compositions)
 }
 
+/// Get an index's composition as of a specific rebalance date, joined with ticker/name -
+/// the historical counterpart to `get_index_composition_with_companies`, used to replay a
+/// backtest against the index's actual rebalance history instead of its current holdings
+pub async fn get_index_composition_with_companies_as_of(
+    pool: &PgPool,
+    index_name: &str,
+    rebalance_date: NaiveDate,
+) -> Result<Vec<CompositionWithCompany>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            c.ticker,
+            c.name as company_name,
+            ic.weight,
+            c.market_cap,
+            c.space_score,
+            c.segments
+        FROM index_compositions ic
+        JOIN companies c ON ic.company_id = c.id
+        WHERE ic.index_name = $1 AND ic.rebalance_date = $2
+        ORDER BY ic.weight DESC
+        "#,
+        index_name,
+        rebalance_date
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let compositions = rows
+        .into_iter()
+        .map(|row| CompositionWithCompany {
+            ticker: row.ticker,
+            company_name: row.company_name,
+            weight: row.weight as f64,
+            market_cap: row.market_cap,
+            space_score: row.space_score.map(|s| s as f64),
+            segments: row.segments,
+        })
+        .collect();
+
+    Ok(compositions)
+}
+
+/// One surviving ticker's weight before and after a rebalance, as reported by `diff_rebalances`
+#[derive(Debug, Clone)]
+pub struct WeightDelta {
+    pub ticker: String,
+    pub from_weight: f64,
+    pub to_weight: f64,
+}
+
+/// What changed between two of an index's rebalance snapshots
+#[derive(Debug, Clone)]
+pub struct RebalanceDiff {
+    pub added: Vec<String>,
+    pub dropped: Vec<String>,
+    pub weight_deltas: Vec<WeightDelta>,
+}
+
+/// Compare an index's composition at `from` against `to`, returning tickers added, tickers
+/// dropped, and the weight change for every ticker present in both snapshots. Builds on
+/// `get_index_composition_with_companies_as_of`, so `from`/`to` should come from
+/// `get_index_rebalance_dates` to guarantee a snapshot actually exists on those dates.
+pub async fn diff_rebalances(
+    pool: &PgPool,
+    index_name: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<RebalanceDiff> {
+    let from_composition = get_index_composition_with_companies_as_of(pool, index_name, from).await?;
+    let to_composition = get_index_composition_with_companies_as_of(pool, index_name, to).await?;
+
+    let from_by_ticker: std::collections::BTreeMap<&str, f64> = from_composition
+        .iter()
+        .map(|c| (c.ticker.as_str(), c.weight))
+        .collect();
+    let to_by_ticker: std::collections::BTreeMap<&str, f64> = to_composition
+        .iter()
+        .map(|c| (c.ticker.as_str(), c.weight))
+        .collect();
+
+    let mut added: Vec<String> = to_by_ticker
+        .keys()
+        .filter(|ticker| !from_by_ticker.contains_key(*ticker))
+        .map(|ticker| ticker.to_string())
+        .collect();
+    added.sort();
+
+    let mut dropped: Vec<String> = from_by_ticker
+        .keys()
+        .filter(|ticker| !to_by_ticker.contains_key(*ticker))
+        .map(|ticker| ticker.to_string())
+        .collect();
+    dropped.sort();
+
+    let mut weight_deltas: Vec<WeightDelta> = from_by_ticker
+        .iter()
+        .filter_map(|(ticker, from_weight)| {
+            to_by_ticker.get(ticker).map(|to_weight| WeightDelta {
+                ticker: ticker.to_string(),
+                from_weight: *from_weight,
+                to_weight: *to_weight,
+            })
+        })
+        .collect();
+    weight_deltas.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+    Ok(RebalanceDiff {
+        added,
+        dropped,
+        weight_deltas,
+    })
+}
+
+/// Composable filter/sort options for screening an index's current composition
+#[derive(Debug, Clone, Default)]
+pub struct CompositionScreenFilter {
+    pub min_weight: Option<f64>,
+    pub max_weight: Option<f64>,
+    pub min_market_cap: Option<i64>,
+    pub min_space_revenue_pct: Option<f32>,
+    pub segment: Option<String>,
+    pub sort_by: Option<String>,
+    pub order: Option<String>,
+}
+
+/// Get an index's current composition filtered/sorted by composable predicates
+pub async fn get_index_composition_screened(
+    pool: &PgPool,
+    index_name: &str,
+    filter: &CompositionScreenFilter,
+) -> Result<Vec<CompositionWithCompany>> {
+    let mut query = QueryBuilder::new(
+        r#"
+        SELECT
+            c.ticker,
+            c.name as company_name,
+            ic.weight,
+            c.market_cap,
+            c.space_score,
+            c.segments
+        FROM index_compositions ic
+        JOIN companies c ON ic.company_id = c.id
+        WHERE ic.index_name =
+        "#,
+    );
+    query.push_bind(index_name);
+    query.push(
+        r#" AND ic.rebalance_date = (SELECT MAX(rebalance_date) FROM index_compositions WHERE index_name = "#,
+    );
+    query.push_bind(index_name);
+    query.push(")");
+
+    if let Some(min_weight) = filter.min_weight {
+        query.push(" AND ic.weight >= ").push_bind(min_weight as f32);
+    }
+    if let Some(max_weight) = filter.max_weight {
+        query.push(" AND ic.weight <= ").push_bind(max_weight as f32);
+    }
+    if let Some(min_market_cap) = filter.min_market_cap {
+        query.push(" AND c.market_cap >= ").push_bind(min_market_cap);
+    }
+    if let Some(min_space_revenue_pct) = filter.min_space_revenue_pct {
+        query
+            .push(" AND c.space_score >= ")
+            .push_bind(min_space_revenue_pct / 100.0);
+    }
+    if let Some(segment) = &filter.segment {
+        query.push(" AND c.segments @> ARRAY[").push_bind(segment).push("]");
+    }
+
+    let sort_column = match filter.sort_by.as_deref() {
+        Some("market_cap") => "c.market_cap",
+        Some("space_revenue_pct") => "c.space_score",
+        _ => "ic.weight",
+    };
+    let sort_direction = match filter.order.as_deref() {
+        Some("asc") => "ASC",
+        _ => "DESC",
+    };
+    query.push(format!(" ORDER BY {} {}", sort_column, sort_direction));
+
+    let rows = query
+        .build_query_as::<CompositionRow>()
+        .fetch_all(pool)
+        .await?;
+
+    let compositions = rows
+        .into_iter()
+        .map(|row| CompositionWithCompany {
+            ticker: row.ticker,
+            company_name: row.company_name,
+            weight: row.weight as f64,
+            market_cap: row.market_cap,
+            space_score: row.space_score.map(|s| s as f64),
+            segments: row.segments,
+        })
+        .collect();
+
+    Ok(compositions)
+}
+
+#[derive(sqlx::FromRow)]
+struct CompositionRow {
+    ticker: String,
+    company_name: String,
+    weight: f32,
+    market_cap: Option<i64>,
+    space_score: Option<f32>,
+    segments: Option<Vec<String>>,
+}
+
 /// Get index metadata
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IndexMetadata {
     pub index_name: String,
     pub num_constituents: i32,
@@ -470,3 +1163,55 @@ pub async fn get_index_metadata(pool: &PgPool, index_name: &str) -> Result<Optio
         latest_return: None, // We don't have total_return field in the table
     }))
 }
+
+// ============================================================================
+// Database Stats
+// ============================================================================
+
+/// Row count for one table, as reported by `get_database_stats`
+#[derive(Debug, Clone)]
+pub struct TableRowCount {
+    pub table_name: String,
+    pub row_count: i64,
+}
+
+/// Per-table row counts plus the most recent `updated_at` across `companies`, used by
+/// `db status` to report on the database's actual contents instead of just "connection ok"
+#[derive(Debug, Clone)]
+pub struct DatabaseStats {
+    pub table_row_counts: Vec<TableRowCount>,
+    pub last_company_update: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+const STATS_TABLES: &[&str] = &[
+    "companies",
+    "fundamentals",
+    "index_compositions",
+    "index_performance",
+    "corporate_actions",
+    "index_registry",
+];
+
+/// Gather row counts for every table and the most recent company update time
+pub async fn get_database_stats(pool: &PgPool) -> Result<DatabaseStats> {
+    let mut table_row_counts = Vec::with_capacity(STATS_TABLES.len());
+    for table in STATS_TABLES {
+        let row_count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await?;
+        table_row_counts.push(TableRowCount {
+            table_name: table.to_string(),
+            row_count,
+        });
+    }
+
+    let last_company_update: Option<chrono::DateTime<chrono::Utc>> =
+        sqlx::query_scalar("SELECT MAX(updated_at) FROM companies")
+            .fetch_one(pool)
+            .await?;
+
+    Ok(DatabaseStats {
+        table_row_counts,
+        last_company_update,
+    })
+}
@@ -0,0 +1,416 @@
+// Backtest engine - reconstruct a historical index value series under a chosen weighting
+// methodology, reweighting the universe on a fixed cadence and evolving the index value
+// from per-constituent price returns in between.
+
+use crate::weighting::{CompanyMetrics, WeightingAlgorithm};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Full daily adjusted-close history for every ticker in a backtest universe, keyed by
+/// ticker then date
+pub type PriceHistoryByTicker = BTreeMap<String, BTreeMap<NaiveDate, f64>>;
+
+/// Known composition weights keyed by the rebalance date they took effect on, as recorded
+/// in the index's actual rebalance history rather than recomputed from an algorithm
+pub type CompositionHistory = BTreeMap<NaiveDate, BTreeMap<String, f32>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RebalanceFrequency {
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+impl RebalanceFrequency {
+    fn period_months(&self) -> i32 {
+        match self {
+            RebalanceFrequency::Monthly => 1,
+            RebalanceFrequency::Quarterly => 3,
+            RebalanceFrequency::Annually => 12,
+        }
+    }
+}
+
+/// A snapshot of a company's weighting-relevant fundamentals as of some reporting date
+#[derive(Debug, Clone, Copy)]
+pub struct CompanyFundamentalPoint {
+    pub market_cap: f64,
+    pub space_revenue_pct: f32,
+    pub revenue_growth_rate: f32,
+}
+
+/// A backtest universe candidate: its fundamentals history (used to recompute weights at
+/// each rebalance) and its daily price history (used to evolve the index value in between)
+#[derive(Debug, Clone)]
+pub struct ConstituentHistory {
+    pub ticker: String,
+    pub name: String,
+    pub segments: Option<String>,
+    pub fundamentals: BTreeMap<NaiveDate, CompanyFundamentalPoint>,
+    pub prices: BTreeMap<NaiveDate, f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestPoint {
+    pub date: NaiveDate,
+    pub index_value: f64,
+    pub daily_return: Option<f64>,
+}
+
+/// Weighting methodology applied at each rebalance
+#[derive(Debug, Clone)]
+pub enum BacktestAlgorithm {
+    /// Score-driven weighting via the existing three-factor model; a pure market-cap or
+    /// score-tilted backtest is just a particular choice of factor weights
+    ThreeFactor(WeightingAlgorithm),
+    /// Equal weight across every universe member with fundamentals as of the rebalance date
+    EqualWeight {
+        max_position_size: f32,
+        min_position_size: f32,
+    },
+}
+
+/// Run a backtest over `dates` (sorted ascending): reweight the `universe` with `algorithm`
+/// on the first date and every time `frequency` has elapsed since the last rebalance,
+/// holding weights constant in between. Constituents missing a price on a given day drop
+/// out of that day's return and the remaining weights are renormalized.
+pub fn run_backtest(
+    dates: &[NaiveDate],
+    universe: &[ConstituentHistory],
+    algorithm: &BacktestAlgorithm,
+    frequency: RebalanceFrequency,
+    base_value: f64,
+) -> Vec<BacktestPoint> {
+    if dates.is_empty() {
+        return vec![];
+    }
+
+    let mut points = Vec::with_capacity(dates.len());
+    let mut index_value = base_value;
+    let mut weights: BTreeMap<String, f32> = BTreeMap::new();
+    let mut last_rebalance: Option<NaiveDate> = None;
+
+    for (i, &date) in dates.iter().enumerate() {
+        let due = match last_rebalance {
+            None => true,
+            Some(last) => months_between(last, date) >= frequency.period_months(),
+        };
+
+        if due {
+            weights = reweight(universe, algorithm, date);
+            last_rebalance = Some(date);
+        }
+
+        let daily_return = if i == 0 {
+            None
+        } else {
+            Some(weighted_daily_return(universe, &weights, dates[i - 1], date))
+        };
+
+        if let Some(r) = daily_return {
+            index_value *= 1.0 + r;
+        }
+
+        points.push(BacktestPoint {
+            date,
+            index_value,
+            daily_return,
+        });
+    }
+
+    points
+}
+
+fn months_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    (to.year() - from.year()) * 12 + (to.month() as i32 - from.month() as i32)
+}
+
+/// Recompute weights from each candidate's most recent fundamentals at or before `date`;
+/// candidates with no fundamentals yet are excluded from that rebalance.
+fn reweight(
+    universe: &[ConstituentHistory],
+    algorithm: &BacktestAlgorithm,
+    date: NaiveDate,
+) -> BTreeMap<String, f32> {
+    let present: Vec<&ConstituentHistory> = universe
+        .iter()
+        .filter(|c| c.fundamentals.range(..=date).next_back().is_some())
+        .collect();
+
+    match algorithm {
+        BacktestAlgorithm::ThreeFactor(algo) => {
+            let companies: Vec<CompanyMetrics> = present
+                .iter()
+                .map(|c| {
+                    let (_, point) = c.fundamentals.range(..=date).next_back().unwrap();
+                    CompanyMetrics {
+                        ticker: c.ticker.clone(),
+                        name: c.name.clone(),
+                        market_cap: point.market_cap,
+                        space_revenue_pct: point.space_revenue_pct,
+                        revenue_growth_rate: point.revenue_growth_rate,
+                        segments: c.segments.clone(),
+                    }
+                })
+                .collect();
+
+            algo.calculate_weights(companies)
+                .into_iter()
+                .map(|c| (c.ticker, c.weight))
+                .collect()
+        }
+        BacktestAlgorithm::EqualWeight {
+            max_position_size,
+            min_position_size,
+        } => equal_weights(&present, *max_position_size, *min_position_size),
+    }
+}
+
+/// Assign each of `present` the same weight, clamp to the position size bounds, and
+/// renormalize so the clamped weights still sum to 1.0 (same clamp-then-renormalize
+/// approach as the three-factor model's position size constraints)
+fn equal_weights(
+    present: &[&ConstituentHistory],
+    max_position_size: f32,
+    min_position_size: f32,
+) -> BTreeMap<String, f32> {
+    if present.is_empty() {
+        return BTreeMap::new();
+    }
+
+    let raw_weight = 1.0 / present.len() as f32;
+    let clamped: Vec<f32> = present
+        .iter()
+        .map(|_| raw_weight.clamp(min_position_size, max_position_size))
+        .collect();
+    let clamped_sum: f32 = clamped.iter().sum();
+
+    present
+        .iter()
+        .zip(clamped.iter())
+        .map(|(c, &w)| (c.ticker.clone(), w / clamped_sum))
+        .collect()
+}
+
+/// Weighted return of the held basket from `previous_date` to `date`, renormalized over
+/// only the constituents with a price on both days.
+fn weighted_daily_return(
+    universe: &[ConstituentHistory],
+    weights: &BTreeMap<String, f32>,
+    previous_date: NaiveDate,
+    date: NaiveDate,
+) -> f64 {
+    let mut weighted_sum = 0.0_f64;
+    let mut weight_total = 0.0_f64;
+
+    for constituent in universe {
+        let Some(&weight) = weights.get(&constituent.ticker) else {
+            continue;
+        };
+        let (Some(&prev_price), Some(&price)) = (
+            constituent.prices.get(&previous_date),
+            constituent.prices.get(&date),
+        ) else {
+            continue;
+        };
+        if prev_price <= 0.0 {
+            continue;
+        }
+
+        weighted_sum += weight as f64 * ((price / prev_price) - 1.0);
+        weight_total += weight as f64;
+    }
+
+    if weight_total > 0.0 {
+        weighted_sum / weight_total
+    } else {
+        0.0
+    }
+}
+
+/// Run a backtest over `dates` (sorted ascending) using weights already recorded in
+/// `composition_history` instead of recomputing them from fundamentals: holds the most
+/// recently effective composition constant and reinvests at each new rebalance date,
+/// same as `run_backtest`. At every date, a composition ticker missing a price (not yet
+/// listed, or the provider has no history for it) is dropped from that day's basket and
+/// the remaining weights are renormalized to sum to 1.0.
+pub fn run_backtest_from_composition(
+    dates: &[NaiveDate],
+    composition_history: &CompositionHistory,
+    prices: &PriceHistoryByTicker,
+    base_value: f64,
+) -> Vec<BacktestPoint> {
+    if dates.is_empty() {
+        return vec![];
+    }
+
+    let mut points = Vec::with_capacity(dates.len());
+    let mut index_value = base_value;
+    let mut weights: BTreeMap<String, f32> = BTreeMap::new();
+
+    for (i, &date) in dates.iter().enumerate() {
+        if let Some(composition) = composition_history.get(&date) {
+            weights = renormalize_to_available(composition, prices, date);
+        }
+
+        let daily_return = if i == 0 {
+            None
+        } else {
+            Some(weighted_return_from_prices(prices, &weights, dates[i - 1], date))
+        };
+
+        if let Some(r) = daily_return {
+            index_value *= 1.0 + r;
+        }
+
+        points.push(BacktestPoint {
+            date,
+            index_value,
+            daily_return,
+        });
+    }
+
+    points
+}
+
+/// Drop any ticker from `composition` that has no price on `date` yet (pre-IPO, delisted,
+/// or simply missing from the provider's response) and renormalize the rest to sum to 1.0
+fn renormalize_to_available(
+    composition: &BTreeMap<String, f32>,
+    prices: &PriceHistoryByTicker,
+    date: NaiveDate,
+) -> BTreeMap<String, f32> {
+    let available: BTreeMap<String, f32> = composition
+        .iter()
+        .filter(|(ticker, _)| prices.get(*ticker).is_some_and(|series| series.contains_key(&date)))
+        .map(|(ticker, &weight)| (ticker.clone(), weight))
+        .collect();
+
+    let total: f32 = available.values().sum();
+    if total <= 0.0 {
+        return BTreeMap::new();
+    }
+
+    available
+        .into_iter()
+        .map(|(ticker, weight)| (ticker, weight / total))
+        .collect()
+}
+
+/// Same contract as `weighted_daily_return`, but reading prices from a plain
+/// ticker->date->price map instead of `ConstituentHistory`
+fn weighted_return_from_prices(
+    prices: &PriceHistoryByTicker,
+    weights: &BTreeMap<String, f32>,
+    previous_date: NaiveDate,
+    date: NaiveDate,
+) -> f64 {
+    let mut weighted_sum = 0.0_f64;
+    let mut weight_total = 0.0_f64;
+
+    for (ticker, &weight) in weights {
+        let Some(series) = prices.get(ticker) else {
+            continue;
+        };
+        let (Some(&prev_price), Some(&price)) = (series.get(&previous_date), series.get(&date))
+        else {
+            continue;
+        };
+        if prev_price <= 0.0 {
+            continue;
+        }
+
+        weighted_sum += weight as f64 * ((price / prev_price) - 1.0);
+        weight_total += weight as f64;
+    }
+
+    if weight_total > 0.0 {
+        weighted_sum / weight_total
+    } else {
+        0.0
+    }
+}
+
+/// Summary statistics for a backtest run, shaped to drop directly into
+/// `newsletter::NewsletterData`'s `total_return`/`ytd_return`/`vs_sp500` fields
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BacktestSummary {
+    pub total_return: f64,
+    pub ytd_return: f64,
+    pub annualized_volatility: f64,
+    pub max_drawdown: f64,
+    pub vs_sp500: f64,
+}
+
+/// Cumulative return (%) from the first to the last point in the series
+fn cumulative_return(points: &[BacktestPoint]) -> f64 {
+    match (points.first(), points.last()) {
+        (Some(first), Some(last)) if first.index_value > 0.0 => {
+            ((last.index_value / first.index_value) - 1.0) * 100.0
+        }
+        _ => 0.0,
+    }
+}
+
+/// Cumulative return (%) since the first point at or after January 1st of `as_of`'s year
+fn ytd_return(points: &[BacktestPoint], as_of: NaiveDate) -> f64 {
+    let year_start = NaiveDate::from_ymd_opt(as_of.year(), 1, 1).unwrap();
+    let ytd_points: Vec<BacktestPoint> = points
+        .iter()
+        .filter(|p| p.date >= year_start)
+        .cloned()
+        .collect();
+
+    cumulative_return(&ytd_points)
+}
+
+/// Annualized volatility (%) of daily returns, assuming 252 trading days/year
+fn annualized_volatility(points: &[BacktestPoint]) -> f64 {
+    let returns: Vec<f64> = points.iter().filter_map(|p| p.daily_return).collect();
+    if returns.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let variance =
+        returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+
+    variance.sqrt() * 252.0_f64.sqrt() * 100.0
+}
+
+/// Largest peak-to-trough decline (%) over the index value series
+fn max_drawdown(points: &[BacktestPoint]) -> f64 {
+    let Some(first) = points.first() else {
+        return 0.0;
+    };
+
+    let mut peak = first.index_value;
+    let mut max_dd = 0.0_f64;
+
+    for point in points {
+        peak = peak.max(point.index_value);
+        max_dd = max_dd.max((peak - point.index_value) / peak);
+    }
+
+    max_dd * 100.0
+}
+
+/// Build the summary block for a backtest run, comparing its total return against
+/// `benchmark`'s total return over the same window for `vs_sp500`
+pub fn summarize_backtest(points: &[BacktestPoint], benchmark: &[BacktestPoint]) -> BacktestSummary {
+    let as_of = points.last().map(|p| p.date).unwrap_or_else(|| {
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+    });
+
+    let total_return = cumulative_return(points);
+    let benchmark_return = cumulative_return(benchmark);
+
+    BacktestSummary {
+        total_return,
+        ytd_return: ytd_return(points, as_of),
+        annualized_volatility: annualized_volatility(points),
+        max_drawdown: max_drawdown(points),
+        vs_sp500: total_return - benchmark_return,
+    }
+}
@@ -2,7 +2,20 @@
 
 pub mod weighting;
 pub mod backtest;
+pub mod rebalance;
+pub mod simulation;
 
 pub use weighting::{
-    CompanyMetrics, IndexConstituent, IndexSummaryStats, WeightingAlgorithm,
+    CompanyMetrics, EqualWeight, FactorReturnRecord, IndexConstituent, IndexSummaryStats,
+    InverseScoreRankWeight, MarketCapWeight, OptimizeParams, WeightingAlgorithm, WeightingStrategy,
 };
+pub use backtest::{
+    run_backtest, run_backtest_from_composition, summarize_backtest, BacktestAlgorithm,
+    BacktestPoint, BacktestSummary, CompanyFundamentalPoint, CompositionHistory,
+    ConstituentHistory, PriceHistoryByTicker, RebalanceFrequency,
+};
+pub use rebalance::{
+    diff_universe, generate_rebalance_orders, portfolio_nav, Order, OrderSide, Position,
+    UniverseChanges,
+};
+pub use simulation::{simulate_concentration, ConcentrationReport};
@@ -0,0 +1,164 @@
+// Rebalance order generation - diff freshly calculated target weights against the current
+// portfolio to produce a concrete Buy/Sell order list, sized in whole shares.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A single current holding: shares held and its last traded price
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    pub shares: f64,
+    pub last_price: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub ticker: String,
+    pub side: OrderSide,
+    pub quantity: u64,
+    pub last_price: f64,
+}
+
+/// Tickers that entered or left the universe between the current holdings and the new
+/// target weights - maps directly onto `newsletter::RebalancingChanges`'s `added`/`removed`
+#[derive(Debug, Clone, Default)]
+pub struct UniverseChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Total market value of the current portfolio (shares * last price, summed)
+pub fn portfolio_nav(holdings: &BTreeMap<String, Position>) -> f64 {
+    holdings.values().map(|p| p.shares * p.last_price).sum()
+}
+
+/// Diff `target_weights` (ticker -> target portfolio weight, summing to ~1.0) against
+/// `holdings` to produce a concrete order list: target dollar value = weight *
+/// `portfolio_nav`, converted to whole target shares at the ticker's live price, then
+/// diffed against shares currently held. A ticker with no current holding is a pure Buy;
+/// a ticker dropped from the target universe is fully sold at its last known price.
+/// `target_prices` only needs to cover tickers not already in `holdings` - an existing
+/// holding's `last_price` is used unless overridden.
+pub fn generate_rebalance_orders(
+    holdings: &BTreeMap<String, Position>,
+    target_weights: &BTreeMap<String, f32>,
+    target_prices: &BTreeMap<String, f64>,
+    portfolio_nav: f64,
+) -> Vec<Order> {
+    let tickers: BTreeSet<&String> = holdings.keys().chain(target_weights.keys()).collect();
+    let mut orders = Vec::new();
+
+    for ticker in tickers {
+        let current_shares = holdings.get(ticker).map(|p| p.shares).unwrap_or(0.0);
+        let target_weight = target_weights.get(ticker).copied().unwrap_or(0.0);
+
+        let price = target_prices
+            .get(ticker)
+            .or_else(|| holdings.get(ticker).map(|p| &p.last_price))
+            .copied();
+        let Some(price) = price.filter(|&p| p > 0.0) else {
+            continue;
+        };
+
+        let target_shares = (target_weight as f64 * portfolio_nav / price).round();
+        let delta = target_shares - current_shares.round();
+
+        if delta.abs() < 1.0 {
+            continue;
+        }
+
+        orders.push(Order {
+            ticker: ticker.clone(),
+            side: if delta > 0.0 { OrderSide::Buy } else { OrderSide::Sell },
+            quantity: delta.abs() as u64,
+            last_price: price,
+        });
+    }
+
+    orders
+}
+
+/// Tickers present in `target_weights` but not `holdings` are additions; tickers present
+/// in `holdings` but not `target_weights` are removals
+pub fn diff_universe(
+    holdings: &BTreeMap<String, Position>,
+    target_weights: &BTreeMap<String, f32>,
+) -> UniverseChanges {
+    UniverseChanges {
+        added: target_weights
+            .keys()
+            .filter(|t| !holdings.contains_key(*t))
+            .cloned()
+            .collect(),
+        removed: holdings
+            .keys()
+            .filter(|t| !target_weights.contains_key(*t))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_ticker_is_a_buy() {
+        let holdings = BTreeMap::new();
+        let mut target_weights = BTreeMap::new();
+        target_weights.insert("RKLB".to_string(), 1.0);
+        let mut target_prices = BTreeMap::new();
+        target_prices.insert("RKLB".to_string(), 10.0);
+
+        let orders = generate_rebalance_orders(&holdings, &target_weights, &target_prices, 1000.0);
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Buy);
+        assert_eq!(orders[0].quantity, 100);
+    }
+
+    #[test]
+    fn test_dropped_ticker_is_a_full_sell() {
+        let mut holdings = BTreeMap::new();
+        holdings.insert(
+            "ASTS".to_string(),
+            Position {
+                shares: 50.0,
+                last_price: 20.0,
+            },
+        );
+        let target_weights = BTreeMap::new();
+
+        let orders =
+            generate_rebalance_orders(&holdings, &target_weights, &BTreeMap::new(), 0.0);
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Sell);
+        assert_eq!(orders[0].quantity, 50);
+    }
+
+    #[test]
+    fn test_diff_universe_added_and_removed() {
+        let mut holdings = BTreeMap::new();
+        holdings.insert(
+            "ASTS".to_string(),
+            Position {
+                shares: 10.0,
+                last_price: 1.0,
+            },
+        );
+        let mut target_weights = BTreeMap::new();
+        target_weights.insert("RKLB".to_string(), 1.0);
+
+        let changes = diff_universe(&holdings, &target_weights);
+
+        assert_eq!(changes.added, vec!["RKLB".to_string()]);
+        assert_eq!(changes.removed, vec!["ASTS".to_string()]);
+    }
+}
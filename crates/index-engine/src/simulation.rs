@@ -0,0 +1,190 @@
+// Monte Carlo concentration-risk simulator - repeatedly resamples the index's weight
+// distribution to estimate how top-heavy it is under resampling, backed by Vose's alias
+// method so each draw is O(1) regardless of universe size.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::weighting::IndexConstituent;
+
+/// Matches `WeightingAlgorithm::default()`'s `max_position_size` - the single-name cap a
+/// concentration report checks draws against.
+const DEFAULT_MAX_POSITION_SIZE: f32 = 0.10;
+
+/// O(1) weighted sampler built via Vose's alias method: each weight is scaled by `n` so the
+/// mean is 1, then `small`/`large` stacks are drained pairwise until every slot holds either
+/// its own probability or a borrowed "alias" index to fall back to.
+struct AliasSampler {
+    prob: Vec<f32>,
+    alias: Vec<usize>,
+}
+
+impl AliasSampler {
+    fn new(weights: &[f32]) -> Self {
+        let n = weights.len();
+        let mut scaled: Vec<f32> = weights.iter().map(|&w| w * n as f32).collect();
+        let mut prob = vec![0.0f32; n];
+        let mut alias = vec![0usize; n];
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (i, &s) in scaled.iter().enumerate() {
+            if s < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        // Leftover entries only miss the 1.0 mark by floating-point rounding - treat them
+        // as certain to land on themselves.
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.0;
+        }
+
+        Self { prob, alias }
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> usize {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f32>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// Concentration-risk metrics estimated by resampling an index's constituent weights
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationReport {
+    pub draws: usize,
+    pub trials: usize,
+    /// Average share of total weight held by the 5 largest names across all trials
+    pub expected_top5_weight_share: f32,
+    /// Average of `1 / sum(weight_i^2)` across trials - the Herfindahl-implied number of
+    /// equally-weighted holdings that would produce the same concentration
+    pub effective_num_holdings: f32,
+    /// Fraction of trials in which a single name's resampled weight exceeded
+    /// `DEFAULT_MAX_POSITION_SIZE`
+    pub prob_exceeds_max_position: f32,
+}
+
+/// Resample `constituents` by `weight` (via `AliasSampler`) `draws` times per trial, over
+/// `trials` trials, and report the resulting concentration metrics.
+pub fn simulate_concentration(
+    constituents: &[IndexConstituent],
+    draws: usize,
+    trials: usize,
+) -> ConcentrationReport {
+    if constituents.is_empty() || draws == 0 || trials == 0 {
+        return ConcentrationReport {
+            draws,
+            trials,
+            expected_top5_weight_share: 0.0,
+            effective_num_holdings: 0.0,
+            prob_exceeds_max_position: 0.0,
+        };
+    }
+
+    let weights: Vec<f32> = constituents.iter().map(|c| c.weight).collect();
+    let sampler = AliasSampler::new(&weights);
+    let mut rng = rand::thread_rng();
+
+    let mut top5_share_total = 0.0f32;
+    let mut effective_n_total = 0.0f32;
+    let mut exceed_trials = 0usize;
+
+    for _ in 0..trials {
+        let mut counts = vec![0u32; constituents.len()];
+        for _ in 0..draws {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+
+        let mut shares: Vec<f32> = counts
+            .iter()
+            .map(|&count| count as f32 / draws as f32)
+            .collect();
+        shares.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+        top5_share_total += shares.iter().take(5).sum::<f32>();
+
+        let herfindahl: f32 = shares.iter().map(|share| share * share).sum();
+        if herfindahl > 0.0 {
+            effective_n_total += 1.0 / herfindahl;
+        }
+
+        if shares[0] > DEFAULT_MAX_POSITION_SIZE {
+            exceed_trials += 1;
+        }
+    }
+
+    ConcentrationReport {
+        draws,
+        trials,
+        expected_top5_weight_share: top5_share_total / trials as f32,
+        effective_num_holdings: effective_n_total / trials as f32,
+        prob_exceeds_max_position: exceed_trials as f32 / trials as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constituent(ticker: &str, weight: f32) -> IndexConstituent {
+        IndexConstituent {
+            ticker: ticker.to_string(),
+            name: ticker.to_string(),
+            market_cap: 0.0,
+            space_revenue_pct: 0.0,
+            revenue_growth_rate: 0.0,
+            raw_score: 0.0,
+            weight,
+            rank: 0,
+            segments: None,
+        }
+    }
+
+    #[test]
+    fn equal_weights_give_full_effective_holdings() {
+        let constituents: Vec<IndexConstituent> =
+            (0..10).map(|i| constituent(&i.to_string(), 0.10)).collect();
+
+        let report = simulate_concentration(&constituents, 5_000, 50);
+
+        assert!(report.effective_num_holdings > 8.0);
+        assert!(report.expected_top5_weight_share < 0.75);
+    }
+
+    #[test]
+    fn one_dominant_name_is_flagged_as_concentrated() {
+        let mut constituents: Vec<IndexConstituent> =
+            (0..9).map(|i| constituent(&i.to_string(), 0.02)).collect();
+        constituents.push(constituent("DOMINANT", 0.82));
+
+        let report = simulate_concentration(&constituents, 5_000, 50);
+
+        assert!(report.prob_exceeds_max_position > 0.9);
+        assert!(report.effective_num_holdings < 3.0);
+    }
+
+    #[test]
+    fn empty_universe_reports_zeros() {
+        let report = simulate_concentration(&[], 1_000, 10);
+
+        assert_eq!(report.expected_top5_weight_share, 0.0);
+        assert_eq!(report.effective_num_holdings, 0.0);
+    }
+}
@@ -38,6 +38,242 @@ pub struct WeightingAlgorithm {
     pub growth_weight: f32,
     pub max_position_size: f32,
     pub min_position_size: f32,
+    /// Hard cap on the number of constituents `calculate_weights` keeps. When set, only the
+    /// top-N by raw score survive; `None` leaves the universe uncapped.
+    pub max_constituents: Option<usize>,
+}
+
+/// A pluggable index weighting methodology - turns a universe of company metrics into ranked,
+/// normalized index weights. `AppState` holds one behind a `Box`/`Arc` selected by config, so an
+/// index can be rebalanced under a different methodology without touching callers.
+pub trait WeightingStrategy: Send + Sync {
+    fn calculate_weights(&self, companies: Vec<CompanyMetrics>) -> Vec<IndexConstituent>;
+}
+
+/// The 40/30/30 three-factor linear model - `WeightingAlgorithm`'s own `calculate_weights`
+impl WeightingStrategy for WeightingAlgorithm {
+    fn calculate_weights(&self, companies: Vec<CompanyMetrics>) -> Vec<IndexConstituent> {
+        WeightingAlgorithm::calculate_weights(self, companies)
+    }
+}
+
+/// Sort constituents by weight descending and assign sequential ranks - shared by the
+/// `EqualWeight` and `MarketCapWeight` strategies below
+fn finalize_ranking(constituents: &mut [IndexConstituent]) {
+    constituents.sort_by(|a, b| b.weight.partial_cmp(&a.weight).unwrap());
+    for (i, constituent) in constituents.iter_mut().enumerate() {
+        constituent.rank = i + 1;
+    }
+}
+
+/// Weight every constituent equally (1/n), ignoring space revenue, market cap, and growth
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EqualWeight;
+
+impl WeightingStrategy for EqualWeight {
+    fn calculate_weights(&self, companies: Vec<CompanyMetrics>) -> Vec<IndexConstituent> {
+        if companies.is_empty() {
+            return vec![];
+        }
+
+        let weight = 1.0 / companies.len() as f32;
+
+        let mut constituents: Vec<IndexConstituent> = companies
+            .into_iter()
+            .map(|company| IndexConstituent {
+                ticker: company.ticker,
+                name: company.name,
+                market_cap: company.market_cap,
+                space_revenue_pct: company.space_revenue_pct,
+                revenue_growth_rate: company.revenue_growth_rate,
+                raw_score: weight,
+                weight,
+                rank: 0,
+                segments: company.segments,
+            })
+            .collect();
+
+        finalize_ranking(&mut constituents);
+        constituents
+    }
+}
+
+/// Weight constituents proportionally to raw market capitalization, uninfluenced by space
+/// revenue or growth
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarketCapWeight;
+
+impl WeightingStrategy for MarketCapWeight {
+    fn calculate_weights(&self, companies: Vec<CompanyMetrics>) -> Vec<IndexConstituent> {
+        if companies.is_empty() {
+            return vec![];
+        }
+
+        let total_market_cap: f64 = companies.iter().map(|c| c.market_cap).sum();
+
+        let mut constituents: Vec<IndexConstituent> = companies
+            .into_iter()
+            .map(|company| {
+                let weight = if total_market_cap > 0.0 {
+                    (company.market_cap / total_market_cap) as f32
+                } else {
+                    0.0
+                };
+
+                IndexConstituent {
+                    ticker: company.ticker,
+                    name: company.name,
+                    market_cap: company.market_cap,
+                    space_revenue_pct: company.space_revenue_pct,
+                    revenue_growth_rate: company.revenue_growth_rate,
+                    raw_score: company.market_cap as f32,
+                    weight,
+                    rank: 0,
+                    segments: company.segments,
+                }
+            })
+            .collect();
+
+        finalize_ranking(&mut constituents);
+        constituents
+    }
+}
+
+/// Ranks constituents by the standard three-factor composite score, then re-weights them by
+/// inverse rank (1/rank, renormalized) instead of by the raw score magnitude - a cruder but
+/// more rank-stable alternative to `WeightingAlgorithm`'s score-proportional weights
+#[derive(Debug, Clone)]
+pub struct InverseScoreRankWeight {
+    base: WeightingAlgorithm,
+}
+
+impl Default for InverseScoreRankWeight {
+    fn default() -> Self {
+        Self {
+            base: WeightingAlgorithm::default(),
+        }
+    }
+}
+
+impl WeightingStrategy for InverseScoreRankWeight {
+    fn calculate_weights(&self, companies: Vec<CompanyMetrics>) -> Vec<IndexConstituent> {
+        let mut constituents = self.base.calculate_weights(companies);
+        if constituents.is_empty() {
+            return constituents;
+        }
+
+        let inverse_ranks: Vec<f32> = constituents.iter().map(|c| 1.0 / c.rank as f32).collect();
+        let total: f32 = inverse_ranks.iter().sum();
+
+        for (constituent, inverse_rank) in constituents.iter_mut().zip(inverse_ranks) {
+            constituent.weight = inverse_rank / total;
+        }
+
+        constituents
+    }
+}
+
+/// One historical observation for fitting the three factor weights: a company's normalized
+/// factor scores in a period, its realized forward return, and how much this observation
+/// should count (e.g. number of underlying samples it was averaged from)
+#[derive(Debug, Clone)]
+pub struct FactorReturnRecord {
+    pub space_revenue_score: f32,
+    pub market_cap_score: f32,
+    pub growth_score: f32,
+    pub realized_return: f32,
+    pub weight: f32,
+}
+
+/// Parameters for `WeightingAlgorithm::optimize_weights`'s gradient descent loop
+#[derive(Debug, Clone)]
+pub struct OptimizeParams {
+    pub learning_rate: f32,
+    pub tolerance: f32,
+    pub max_iterations: usize,
+}
+
+impl Default for OptimizeParams {
+    fn default() -> Self {
+        Self {
+            learning_rate: 0.1,
+            tolerance: 1e-6,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+/// Maps `0..len` through `f` into a preallocated, pre-sized `Vec` indexed by position, so
+/// output order is reproducible regardless of how work is scheduled. Behind the `parallel`
+/// feature this runs across the rayon global thread pool (as fsrs does for its retention
+/// calculation, ~10x on multi-core for large universes); otherwise it's a plain sequential
+/// map.
+#[cfg(feature = "parallel")]
+fn index_map_f32(len: usize, f: impl Fn(usize) -> f32 + Sync) -> Vec<f32> {
+    use rayon::prelude::*;
+
+    let mut out = vec![0.0f32; len];
+    out.par_iter_mut().enumerate().for_each(|(i, slot)| {
+        *slot = f(i);
+    });
+    out
+}
+
+#[cfg(not(feature = "parallel"))]
+fn index_map_f32(len: usize, f: impl Fn(usize) -> f32) -> Vec<f32> {
+    (0..len).map(f).collect()
+}
+
+/// Linearly-interpolated percentile of `sorted` (must already be sorted ascending) at
+/// quantile `q` in `[0, 1]` - fractional index `h = (n-1)*q`, interpolated between the floor
+/// and ceil ranks
+fn percentile(sorted: &[f32], q: f32) -> f32 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let h = (sorted.len() - 1) as f32 * q;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+    sorted[lo] + (h - lo as f32) * (sorted[hi] - sorted[lo])
+}
+
+/// Winsorize `values` to `[p_lower_q, p_upper_q]` (computed via linear-interpolated
+/// percentiles) and rescale that clamped range to 0-100, so a couple of outliers no longer
+/// stretch the whole scale and compress everyone else
+fn normalize_winsorized(values: &[f32], lower_q: f32, upper_q: f32) -> Vec<f32> {
+    if values.is_empty() {
+        return vec![];
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let p_lower = percentile(&sorted, lower_q);
+    let p_upper = percentile(&sorted, upper_q);
+
+    if (p_upper - p_lower).abs() < 0.0001 {
+        return vec![50.0; values.len()];
+    }
+
+    index_map_f32(values.len(), |i| {
+        (values[i].clamp(p_lower, p_upper) - p_lower) / (p_upper - p_lower) * 100.0
+    })
+}
+
+/// Project a weight vector onto the probability simplex: clamp negatives to 0, then
+/// renormalize so the entries sum to 1.0. Falls back to an equal split if every entry
+/// clamps to 0 (avoids dividing by zero).
+fn project_onto_simplex(w: &mut [f32; 3]) {
+    for x in w.iter_mut() {
+        *x = x.max(0.0);
+    }
+    let sum: f32 = w.iter().sum();
+    if sum <= 0.0 {
+        *w = [1.0 / 3.0; 3];
+        return;
+    }
+    for x in w.iter_mut() {
+        *x /= sum;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,6 +308,7 @@ impl WeightingAlgorithm {
             growth_weight,
             max_position_size,
             min_position_size,
+            max_constituents: None,
         })
     }
 
@@ -83,90 +320,49 @@ impl WeightingAlgorithm {
             growth_weight: 0.3,
             max_position_size: 0.10, // 10%
             min_position_size: 0.01, // 1%
+            max_constituents: None,
         }
     }
 
-    /// Normalize market cap using log transformation
+    /// Cap the index to at most `max_constituents` names - `calculate_weights` keeps only the
+    /// top-N by raw score and drops the rest before any weight math runs
+    pub fn with_max_constituents(mut self, max_constituents: usize) -> Self {
+        self.max_constituents = Some(max_constituents);
+        self
+    }
+
+    /// Normalize market cap using log transformation, then winsorize so a handful of mega-caps
+    /// don't stretch the whole 0-100 scale
     fn normalize_market_cap(&self, market_caps: &[f64]) -> Vec<f32> {
         if market_caps.is_empty() {
             return vec![];
         }
 
         // Log10 transform to dampen large-cap dominance
-        let log_caps: Vec<f32> = market_caps
-            .iter()
-            .map(|&cap| {
-                if cap > 0.0 {
-                    (cap as f64).log10() as f32
-                } else {
-                    0.0
-                }
-            })
-            .collect();
-
-        // Find min and max
-        let min_val = log_caps
-            .iter()
-            .filter(|&&x| x > 0.0)
-            .copied()
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
-        let max_val = log_caps
-            .iter()
-            .copied()
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
-
-        // Normalize to 0-100 scale
-        if (max_val - min_val).abs() < 0.0001 {
-            return vec![50.0; log_caps.len()];
-        }
-
-        log_caps
-            .iter()
-            .map(|&val| {
-                if val > 0.0 {
-                    (val - min_val) / (max_val - min_val) * 100.0
-                } else {
-                    0.0
-                }
-            })
-            .collect()
+        let log_caps: Vec<f32> = index_map_f32(market_caps.len(), |i| {
+            let cap = market_caps[i];
+            if cap > 0.0 {
+                (cap as f64).log10() as f32
+            } else {
+                0.0
+            }
+        });
+
+        normalize_winsorized(&log_caps, 0.05, 0.95)
     }
 
-    /// Normalize growth rates with clipping to handle extremes
+    /// Normalize growth rates, clipping extremes (-50% to +200%) before winsorizing so the
+    /// scale isn't dominated by one hyper-growth name
     fn normalize_growth(&self, growth_rates: &[f32]) -> Vec<f32> {
         if growth_rates.is_empty() {
             return vec![];
         }
 
         // Clip extreme values (-50% to +200%)
-        let clipped: Vec<f32> = growth_rates
-            .iter()
-            .map(|&rate| rate.clamp(-50.0, 200.0))
-            .collect();
-
-        // Find min and max
-        let min_val = clipped
-            .iter()
-            .copied()
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
-        let max_val = clipped
-            .iter()
-            .copied()
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
-
-        // Normalize to 0-100 scale
-        if (max_val - min_val).abs() < 0.0001 {
-            return vec![50.0; clipped.len()];
-        }
+        let clipped: Vec<f32> =
+            index_map_f32(growth_rates.len(), |i| growth_rates[i].clamp(-50.0, 200.0));
 
-        clipped
-            .iter()
-            .map(|&val| (val - min_val) / (max_val - min_val) * 100.0)
-            .collect()
+        normalize_winsorized(&clipped, 0.05, 0.95)
     }
 
     /// Calculate index weights for all companies
@@ -184,21 +380,31 @@ impl WeightingAlgorithm {
         let norm_market_cap = self.normalize_market_cap(&market_caps);
         let norm_growth = self.normalize_growth(&growth_rates);
 
-        // Calculate raw scores
-        let raw_scores: Vec<f32> = (0..companies.len())
-            .map(|i| {
-                norm_space_rev[i] * self.space_revenue_weight
-                    + norm_market_cap[i] * self.market_cap_weight
-                    + norm_growth[i] * self.growth_weight
-            })
-            .collect();
+        // Calculate raw scores over the full universe, before any constituent cap is applied
+        let raw_scores: Vec<f32> = index_map_f32(companies.len(), |i| {
+            norm_space_rev[i] * self.space_revenue_weight
+                + norm_market_cap[i] * self.market_cap_weight
+                + norm_growth[i] * self.growth_weight
+        });
+
+        // Apply the hard constituent cap, if any: keep only the top-N by raw score and drop
+        // the rest before any weight math runs, so clamping/renormalization only ever sees
+        // survivors
+        let mut survivor_indices: Vec<usize> = (0..companies.len()).collect();
+        if let Some(max_n) = self.max_constituents {
+            if survivor_indices.len() > max_n {
+                survivor_indices
+                    .sort_by(|&a, &b| raw_scores[b].partial_cmp(&raw_scores[a]).unwrap());
+                survivor_indices.truncate(max_n);
+            }
+        }
 
-        let total_score: f32 = raw_scores.iter().sum();
+        let total_score: f32 = survivor_indices.iter().map(|&i| raw_scores[i]).sum();
 
         // Convert to weights (normalize to sum to 1.0)
-        let mut weights: Vec<f32> = raw_scores
+        let mut weights: Vec<f32> = survivor_indices
             .iter()
-            .map(|&score| score / total_score)
+            .map(|&i| raw_scores[i] / total_score)
             .collect();
 
         // Apply position size constraints
@@ -211,20 +417,27 @@ impl WeightingAlgorithm {
         let weight_sum: f32 = weights.iter().sum();
         weights = weights.iter().map(|&w| w / weight_sum).collect();
 
-        // Create IndexConstituent objects
-        let mut constituents: Vec<IndexConstituent> = companies
+        // Create IndexConstituent objects for survivors only
+        let mut companies: Vec<Option<CompanyMetrics>> =
+            companies.into_iter().map(Some).collect();
+        let mut constituents: Vec<IndexConstituent> = survivor_indices
             .into_iter()
             .enumerate()
-            .map(|(i, company)| IndexConstituent {
-                ticker: company.ticker,
-                name: company.name,
-                market_cap: company.market_cap,
-                space_revenue_pct: company.space_revenue_pct,
-                revenue_growth_rate: company.revenue_growth_rate,
-                raw_score: raw_scores[i],
-                weight: weights[i],
-                rank: 0, // Will be set after sorting
-                segments: company.segments,
+            .map(|(w_i, company_i)| {
+                let company = companies[company_i]
+                    .take()
+                    .expect("survivor_indices has no duplicate entries");
+                IndexConstituent {
+                    ticker: company.ticker,
+                    name: company.name,
+                    market_cap: company.market_cap,
+                    space_revenue_pct: company.space_revenue_pct,
+                    revenue_growth_rate: company.revenue_growth_rate,
+                    raw_score: raw_scores[company_i],
+                    weight: weights[w_i],
+                    rank: 0, // Will be set after sorting
+                    segments: company.segments,
+                }
             })
             .collect();
 
@@ -239,6 +452,69 @@ impl WeightingAlgorithm {
         constituents
     }
 
+    /// Fit `space_revenue_weight`, `market_cap_weight`, and `growth_weight` to historical data
+    /// via gradient descent, instead of assuming the default 40/30/30 split.
+    ///
+    /// Each record's predicted score is `w·factors`; we minimize the weighted mean-squared
+    /// error against realized forward returns. After every step the weights are projected back
+    /// onto the simplex (negatives clamped to 0, then renormalized to sum to 1.0) so `new()`'s
+    /// "weights sum to 1.0" invariant keeps holding. Stops early once the loss stops improving
+    /// by more than `params.tolerance`.
+    pub fn optimize_weights(&mut self, records: &[FactorReturnRecord], params: &OptimizeParams) {
+        if records.is_empty() {
+            return;
+        }
+
+        let mut w = [
+            self.space_revenue_weight,
+            self.market_cap_weight,
+            self.growth_weight,
+        ];
+
+        let loss = |w: &[f32; 3]| -> f32 {
+            records
+                .iter()
+                .map(|r| {
+                    let pred = w[0] * r.space_revenue_score
+                        + w[1] * r.market_cap_score
+                        + w[2] * r.growth_score;
+                    r.weight * (pred - r.realized_return).powi(2)
+                })
+                .sum()
+        };
+
+        let mut prev_loss = loss(&w);
+
+        for _ in 0..params.max_iterations {
+            let mut grad = [0.0f32; 3];
+            for r in records {
+                let pred = w[0] * r.space_revenue_score
+                    + w[1] * r.market_cap_score
+                    + w[2] * r.growth_score;
+                let err = 2.0 * r.weight * (pred - r.realized_return);
+                grad[0] += err * r.space_revenue_score;
+                grad[1] += err * r.market_cap_score;
+                grad[2] += err * r.growth_score;
+            }
+
+            for j in 0..3 {
+                w[j] -= params.learning_rate * grad[j];
+            }
+            project_onto_simplex(&mut w);
+
+            let current_loss = loss(&w);
+            let converged = (prev_loss - current_loss).abs() < params.tolerance;
+            prev_loss = current_loss;
+            if converged {
+                break;
+            }
+        }
+
+        self.space_revenue_weight = w[0];
+        self.market_cap_weight = w[1];
+        self.growth_weight = w[2];
+    }
+
     /// Generate summary statistics for the index
     pub fn summary_stats(&self, constituents: &[IndexConstituent]) -> Option<IndexSummaryStats> {
         if constituents.is_empty() {
@@ -412,6 +688,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_max_constituents_caps_to_top_n_by_raw_score() {
+        let algo = WeightingAlgorithm::default().with_max_constituents(3);
+
+        let companies = vec![
+            CompanyMetrics {
+                ticker: "ASTS".to_string(),
+                name: "AST SpaceMobile".to_string(),
+                market_cap: 19.2e9,
+                space_revenue_pct: 90.0,
+                revenue_growth_rate: 120.0,
+                segments: Some("Satellites".to_string()),
+            },
+            CompanyMetrics {
+                ticker: "RKLB".to_string(),
+                name: "Rocket Lab".to_string(),
+                market_cap: 25.0e9,
+                space_revenue_pct: 80.0,
+                revenue_growth_rate: 50.0,
+                segments: Some("Launch".to_string()),
+            },
+            CompanyMetrics {
+                ticker: "IRDM".to_string(),
+                name: "Iridium".to_string(),
+                market_cap: 1.8e9,
+                space_revenue_pct: 50.0,
+                revenue_growth_rate: 5.0,
+                segments: Some("Satellites".to_string()),
+            },
+            CompanyMetrics {
+                ticker: "GSAT".to_string(),
+                name: "Globalstar".to_string(),
+                market_cap: 6.4e9,
+                space_revenue_pct: 30.0,
+                revenue_growth_rate: 15.0,
+                segments: Some("Satellites".to_string()),
+            },
+            CompanyMetrics {
+                ticker: "SPCE".to_string(),
+                name: "Virgin Galactic".to_string(),
+                market_cap: 0.2e9,
+                space_revenue_pct: 50.0,
+                revenue_growth_rate: -20.0,
+                segments: Some("Launch".to_string()),
+            },
+        ];
+
+        let constituents = algo.calculate_weights(companies);
+
+        assert_eq!(constituents.len(), 3);
+
+        // Weakest 2 by raw score (IRDM, SPCE) should have been dropped
+        let tickers: Vec<&str> = constituents.iter().map(|c| c.ticker.as_str()).collect();
+        assert!(!tickers.contains(&"IRDM"));
+        assert!(!tickers.contains(&"SPCE"));
+
+        // Survivors still sum to 1.0 and are ranked contiguously
+        let total_weight: f32 = constituents.iter().map(|c| c.weight).sum();
+        assert!((total_weight - 1.0).abs() < 0.001);
+        for (i, c) in constituents.iter().enumerate() {
+            assert_eq!(c.rank, i + 1);
+        }
+    }
+
     #[test]
     fn test_summary_stats() {
         let algo = WeightingAlgorithm::default();
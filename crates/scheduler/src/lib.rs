@@ -0,0 +1,157 @@
+// Generic recurring-job scheduler: a time-keyed queue of jobs that sleeps until the
+// earliest one is due, runs it, and reschedules it by its own interval. Patterned on the
+// trend-setter loop in caveman. Concrete jobs (fundamentals refresh, rebalance; no
+// performance-snapshot job is defined) live with whichever binary owns the resources they
+// touch - this crate only knows how to queue and run them.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+/// A unit of recurring work the scheduler dispatches by name
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// Stable identity used to coalesce duplicate pending runs of this job
+    fn name(&self) -> String;
+
+    /// How long after a run finishes before this job is due again
+    fn interval(&self) -> Duration;
+
+    async fn run(&self) -> anyhow::Result<()>;
+}
+
+/// Whether a job's most recent run succeeded
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "status", content = "error")]
+pub enum JobOutcome {
+    Success,
+    Failed(String),
+}
+
+/// A job's externally-observable schedule: when it's next due, and how its last run went
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JobStatus {
+    pub name: String,
+    pub next_run: DateTime<Utc>,
+    pub last_run: Option<DateTime<Utc>>,
+    pub last_outcome: Option<JobOutcome>,
+}
+
+struct StatusEntry {
+    next_run_at: DateTime<Utc>,
+    last_run: Option<DateTime<Utc>>,
+    last_outcome: Option<JobOutcome>,
+}
+
+/// A time-keyed queue of recurring jobs. Duplicate pending runs of the same job (by name)
+/// are coalesced so a slow run never stacks up backlog behind it.
+pub struct Scheduler {
+    queue: Mutex<BTreeMap<tokio::time::Instant, Arc<dyn Job>>>,
+    pending: DashMap<String, ()>,
+    status: DashMap<String, StatusEntry>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            queue: Mutex::new(BTreeMap::new()),
+            pending: DashMap::new(),
+            status: DashMap::new(),
+        }
+    }
+
+    /// Queue `job` to first run after `delay`; it reschedules itself by its own interval
+    /// after that. A job already pending under the same name is left alone.
+    pub async fn schedule(&self, job: Arc<dyn Job>, delay: Duration) {
+        self.enqueue(job, delay).await;
+    }
+
+    async fn enqueue(&self, job: Arc<dyn Job>, delay: Duration) {
+        let name = job.name();
+        if self.pending.contains_key(&name) {
+            return;
+        }
+        self.pending.insert(name.clone(), ());
+
+        let next_run_at = Utc::now() + chrono::Duration::from_std(delay).unwrap_or_default();
+        self.status
+            .entry(name)
+            .and_modify(|s| s.next_run_at = next_run_at)
+            .or_insert(StatusEntry {
+                next_run_at,
+                last_run: None,
+                last_outcome: None,
+            });
+
+        self.queue
+            .lock()
+            .await
+            .insert(tokio::time::Instant::now() + delay, job);
+    }
+
+    /// Run forever: sleep until the earliest queued job is due, run it, then requeue it at
+    /// its own interval
+    pub async fn run(self: Arc<Self>) {
+        loop {
+            let next_instant = {
+                let queue = self.queue.lock().await;
+                queue.keys().next().copied()
+            };
+
+            let Some(next_instant) = next_instant else {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                continue;
+            };
+
+            tokio::time::sleep_until(next_instant).await;
+
+            let job = self.queue.lock().await.remove(&next_instant);
+            let Some(job) = job else { continue };
+
+            let name = job.name();
+            self.pending.remove(&name);
+
+            info!("Running scheduled job {}", name);
+            let outcome = match job.run().await {
+                Ok(()) => JobOutcome::Success,
+                Err(e) => {
+                    error!("Scheduled job {} failed: {}", name, e);
+                    JobOutcome::Failed(e.to_string())
+                }
+            };
+
+            if let Some(mut entry) = self.status.get_mut(&name) {
+                entry.last_run = Some(Utc::now());
+                entry.last_outcome = Some(outcome);
+            }
+
+            let interval = job.interval();
+            self.enqueue(job, interval).await;
+        }
+    }
+
+    /// Snapshot of every job's next-run time and last-run outcome, for callers that want
+    /// to observe the schedule (e.g. the `/api/jobs` route)
+    pub fn status(&self) -> Vec<JobStatus> {
+        self.status
+            .iter()
+            .map(|entry| JobStatus {
+                name: entry.key().clone(),
+                next_run: entry.next_run_at,
+                last_run: entry.last_run,
+                last_outcome: entry.last_outcome.clone(),
+            })
+            .collect()
+    }
+}